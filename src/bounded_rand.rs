@@ -1,4 +1,5 @@
 use super::*;
+use core::ops::{Range, RangeInclusive};
 
 /// Stores the values to sample a number in `0 .. N`
 ///
@@ -71,3 +72,503 @@ impl BoundedRandU32 {
     }
   }
 }
+
+/// Stores the values to sample a number in `0 .. N`, for a `u64` bound.
+///
+/// This works the same as [`BoundedRandU32`], using a `64x64->128` multiply.
+/// On 64-bit targets that's a `u128` multiply, and other targets build it out
+/// of `32x32->64` multiplies instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoundedRandU64 {
+  /// number of possible outputs. outputs will be in `0 .. count`
+  count: u64,
+  /// Multiplication threshold thing.
+  ///
+  /// <https://arxiv.org/abs/1805.10941>
+  threshold: u64,
+}
+impl BoundedRandU64 {
+  /// Constructs a new `BoundedRandU64`.
+  ///
+  /// ## Panics
+  /// If the count is 0.
+  #[inline]
+  pub const fn new(count: u64) -> Self {
+    let threshold = count.wrapping_neg() % count;
+    Self { count, threshold }
+  }
+
+  /// Constructs a new `BoundedRandU64`, or `None` on failure.
+  ///
+  /// ## Failure
+  /// If the count is 0.
+  #[inline]
+  pub const fn try_new(count: u64) -> Option<Self> {
+    if count > 0 {
+      Some(Self::new(count))
+    } else {
+      None
+    }
+  }
+
+  /// The number of possible outputs.
+  #[inline]
+  pub const fn count(self) -> u64 {
+    self.count
+  }
+
+  /// Given a `u64`, place it into this bounded range.
+  ///
+  /// ## Failure
+  /// * If the value is such that it doesn't fit evenly it is rejected.
+  #[inline]
+  pub const fn place_in_range(self, val: u64) -> Option<u64> {
+    let (high_part, low_part) = mul_wide_u64(val, self.count);
+    if low_part < self.threshold {
+      None
+    } else {
+      Some(high_part)
+    }
+  }
+
+  /// Given a gen, sample from the gen until `place_in_range` succeeds.
+  #[inline]
+  pub fn sample<G: Gen32 + ?Sized>(self, gen: &mut G) -> u64 {
+    loop {
+      if let Some(output) = self.place_in_range(gen.next_u64()) {
+        return output;
+      }
+    }
+  }
+}
+
+/// Stores the values to sample a number in `0 .. N`, for a `u16` bound.
+///
+/// This works the same as [`BoundedRandU32`], but each attempt uses only 16
+/// bits of generator output. Each `next_u32` is split into 2 parts, and the
+/// parts not used yet are kept in this value for the next call to
+/// [`sample`](Self::sample). When drawing many small values this needs far
+/// fewer generator calls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoundedRandU16 {
+  /// number of possible outputs. outputs will be in `0 .. count`
+  count: u16,
+  /// Multiplication threshold thing.
+  ///
+  /// <https://arxiv.org/abs/1805.10941>
+  threshold: u16,
+  /// Generator output that hasn't been used yet.
+  bits: u32,
+  /// How many `u16` values are left in `bits`.
+  bits_left: u8,
+}
+impl BoundedRandU16 {
+  /// Constructs a new `BoundedRandU16`.
+  ///
+  /// ## Panics
+  /// If the count is 0.
+  #[inline]
+  pub const fn new(count: u16) -> Self {
+    let threshold = count.wrapping_neg() % count;
+    Self { count, threshold, bits: 0, bits_left: 0 }
+  }
+
+  /// Constructs a new `BoundedRandU16`, or `None` on failure.
+  ///
+  /// ## Failure
+  /// If the count is 0.
+  #[inline]
+  pub const fn try_new(count: u16) -> Option<Self> {
+    if count > 0 {
+      Some(Self::new(count))
+    } else {
+      None
+    }
+  }
+
+  /// The number of possible outputs.
+  #[inline]
+  pub const fn count(self) -> u16 {
+    self.count
+  }
+
+  /// Given a `u16`, place it into this bounded range.
+  ///
+  /// ## Failure
+  /// * If the value is such that it doesn't fit evenly it is rejected.
+  #[inline]
+  pub const fn place_in_range(self, val: u16) -> Option<u16> {
+    let mul: u32 = (val as u32).wrapping_mul(self.count as u32);
+    let low_part: u16 = mul as u16;
+    if low_part < self.threshold {
+      None
+    } else {
+      Some((mul >> 16) as u16)
+    }
+  }
+
+  /// Given a gen, sample from the gen until `place_in_range` succeeds.
+  ///
+  /// Leftover bits from the gen are kept for the next call.
+  #[inline]
+  pub fn sample<G: Gen32 + ?Sized>(&mut self, gen: &mut G) -> u16 {
+    loop {
+      if self.bits_left == 0 {
+        self.bits = gen.next_u32();
+        self.bits_left = 2;
+      }
+      let val = self.bits as u16;
+      self.bits >>= 16;
+      self.bits_left -= 1;
+      if let Some(output) = self.place_in_range(val) {
+        return output;
+      }
+    }
+  }
+}
+
+/// Stores the values to sample a number in `0 .. N`, for a `u8` bound.
+///
+/// This works the same as [`BoundedRandU32`], but each attempt uses only 8
+/// bits of generator output. Each `next_u32` is split into 4 parts, and the
+/// parts not used yet are kept in this value for the next call to
+/// [`sample`](Self::sample). When drawing many small values this needs far
+/// fewer generator calls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoundedRandU8 {
+  /// number of possible outputs. outputs will be in `0 .. count`
+  count: u8,
+  /// Multiplication threshold thing.
+  ///
+  /// <https://arxiv.org/abs/1805.10941>
+  threshold: u8,
+  /// Generator output that hasn't been used yet.
+  bits: u32,
+  /// How many `u8` values are left in `bits`.
+  bits_left: u8,
+}
+impl BoundedRandU8 {
+  /// Constructs a new `BoundedRandU8`.
+  ///
+  /// ## Panics
+  /// If the count is 0.
+  #[inline]
+  pub const fn new(count: u8) -> Self {
+    let threshold = count.wrapping_neg() % count;
+    Self { count, threshold, bits: 0, bits_left: 0 }
+  }
+
+  /// Constructs a new `BoundedRandU8`, or `None` on failure.
+  ///
+  /// ## Failure
+  /// If the count is 0.
+  #[inline]
+  pub const fn try_new(count: u8) -> Option<Self> {
+    if count > 0 {
+      Some(Self::new(count))
+    } else {
+      None
+    }
+  }
+
+  /// The number of possible outputs.
+  #[inline]
+  pub const fn count(self) -> u8 {
+    self.count
+  }
+
+  /// Given a `u8`, place it into this bounded range.
+  ///
+  /// ## Failure
+  /// * If the value is such that it doesn't fit evenly it is rejected.
+  #[inline]
+  pub const fn place_in_range(self, val: u8) -> Option<u8> {
+    let mul: u16 = (val as u16).wrapping_mul(self.count as u16);
+    let low_part: u8 = mul as u8;
+    if low_part < self.threshold {
+      None
+    } else {
+      Some((mul >> 8) as u8)
+    }
+  }
+
+  /// Given a gen, sample from the gen until `place_in_range` succeeds.
+  ///
+  /// Leftover bits from the gen are kept for the next call.
+  #[inline]
+  pub fn sample<G: Gen32 + ?Sized>(&mut self, gen: &mut G) -> u8 {
+    loop {
+      if self.bits_left == 0 {
+        self.bits = gen.next_u32();
+        self.bits_left = 4;
+      }
+      let val = self.bits as u8;
+      self.bits >>= 8;
+      self.bits_left -= 1;
+      if let Some(output) = self.place_in_range(val) {
+        return output;
+      }
+    }
+  }
+}
+
+/// Samples an `i32` from an inclusive range, such as `-5 ..= 5`.
+///
+/// * The output is evenly spread over the range (using a
+///   [`BoundedRandU32`] inside), with no overflow for negative bounds or for
+///   ranges wider than `i32::MAX`.
+/// * The full range `i32::MIN ..= i32::MAX` is allowed, and just uses each
+///   generator output directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RangeI32 {
+  low: i32,
+  /// `None` if the range is every `i32`.
+  bounded: Option<BoundedRandU32>,
+}
+impl RangeI32 {
+  /// Constructs a new `RangeI32`.
+  ///
+  /// ## Panics
+  /// If the range is empty (the start is more than the end).
+  #[inline]
+  pub fn new(range: RangeInclusive<i32>) -> Self {
+    Self::try_new(range).expect("RangeI32::new> Invalid parameters.")
+  }
+
+  /// Constructs a new `RangeI32`, or `None` on failure.
+  ///
+  /// ## Failure
+  /// If the range is empty (the start is more than the end).
+  #[inline]
+  pub fn try_new(range: RangeInclusive<i32>) -> Option<Self> {
+    let (low, high) = range.into_inner();
+    if low > high {
+      return None;
+    }
+    let count = (high.wrapping_sub(low) as u32).wrapping_add(1);
+    Some(Self { low, bounded: BoundedRandU32::try_new(count) })
+  }
+
+  /// The lowest possible output.
+  #[inline]
+  pub const fn low(self) -> i32 {
+    self.low
+  }
+
+  /// The highest possible output.
+  #[inline]
+  pub const fn high(self) -> i32 {
+    match self.bounded {
+      Some(b) => self.low.wrapping_add((b.count() - 1) as i32),
+      None => i32::MAX,
+    }
+  }
+
+  /// Given a gen, sample a value in the range.
+  #[inline]
+  pub fn sample<G: Gen32 + ?Sized>(self, gen: &mut G) -> i32 {
+    match self.bounded {
+      Some(b) => self.low.wrapping_add(b.sample(gen) as i32),
+      None => gen.next_u32() as i32,
+    }
+  }
+}
+
+/// Samples an `i64` from an inclusive range, such as `-5 ..= 5`.
+///
+/// * The output is evenly spread over the range (using a
+///   [`BoundedRandU64`] inside), with no overflow for negative bounds or for
+///   ranges wider than `i64::MAX`.
+/// * The full range `i64::MIN ..= i64::MAX` is allowed, and just uses each
+///   generator output directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RangeI64 {
+  low: i64,
+  /// `None` if the range is every `i64`.
+  bounded: Option<BoundedRandU64>,
+}
+impl RangeI64 {
+  /// Constructs a new `RangeI64`.
+  ///
+  /// ## Panics
+  /// If the range is empty (the start is more than the end).
+  #[inline]
+  pub fn new(range: RangeInclusive<i64>) -> Self {
+    Self::try_new(range).expect("RangeI64::new> Invalid parameters.")
+  }
+
+  /// Constructs a new `RangeI64`, or `None` on failure.
+  ///
+  /// ## Failure
+  /// If the range is empty (the start is more than the end).
+  #[inline]
+  pub fn try_new(range: RangeInclusive<i64>) -> Option<Self> {
+    let (low, high) = range.into_inner();
+    if low > high {
+      return None;
+    }
+    let count = (high.wrapping_sub(low) as u64).wrapping_add(1);
+    Some(Self { low, bounded: BoundedRandU64::try_new(count) })
+  }
+
+  /// The lowest possible output.
+  #[inline]
+  pub const fn low(self) -> i64 {
+    self.low
+  }
+
+  /// The highest possible output.
+  #[inline]
+  pub const fn high(self) -> i64 {
+    match self.bounded {
+      Some(b) => self.low.wrapping_add((b.count() - 1) as i64),
+      None => i64::MAX,
+    }
+  }
+
+  /// Given a gen, sample a value in the range.
+  #[inline]
+  pub fn sample<G: Gen32 + ?Sized>(self, gen: &mut G) -> i64 {
+    match self.bounded {
+      Some(b) => self.low.wrapping_add(b.sample(gen) as i64),
+      None => gen.next_u64() as i64,
+    }
+  }
+}
+
+/// A range that a value can be sampled from, for use with
+/// [`Gen32::gen_range`].
+///
+/// * This is implemented for `a .. b` and `a ..= b` ranges of `u32`, `i32`,
+///   `u64`, `i64`, `usize`, and `f32`.
+/// * Integer outputs are evenly spread over the range, with no overflow for
+///   negative or full-width ranges.
+pub trait SampleRange<T> {
+  /// Samples a value in the range.
+  ///
+  /// ## Panics
+  /// * If the range is empty.
+  /// * For `f32`, if either end isn't finite.
+  fn sample_range<G: Gen32 + ?Sized>(self, gen: &mut G) -> T;
+}
+
+macro_rules! impl_sample_range_int {
+  ($t:ty, $range:ty, $via:ty) => {
+    impl SampleRange<$t> for Range<$t> {
+      #[inline]
+      fn sample_range<G: Gen32 + ?Sized>(self, gen: &mut G) -> $t {
+        assert!(self.start < self.end, "SampleRange::sample_range> Empty range.");
+        <$range>::new((self.start as $via)..=((self.end - 1) as $via)).sample(gen) as $t
+      }
+    }
+    impl SampleRange<$t> for RangeInclusive<$t> {
+      #[inline]
+      fn sample_range<G: Gen32 + ?Sized>(self, gen: &mut G) -> $t {
+        let (low, high) = self.into_inner();
+        assert!(low <= high, "SampleRange::sample_range> Empty range.");
+        <$range>::new((low as $via)..=(high as $via)).sample(gen) as $t
+      }
+    }
+  };
+}
+impl_sample_range_int!(i32, RangeI32, i32);
+impl_sample_range_int!(i64, RangeI64, i64);
+
+// Unsigned ranges are flipped into signed ones by flipping the top bit, which
+// keeps them in the same order.
+macro_rules! impl_sample_range_uint {
+  ($t:ty, $range:ty, $via:ty) => {
+    impl SampleRange<$t> for Range<$t> {
+      #[inline]
+      fn sample_range<G: Gen32 + ?Sized>(self, gen: &mut G) -> $t {
+        assert!(self.start < self.end, "SampleRange::sample_range> Empty range.");
+        (self.start..=(self.end - 1)).sample_range(gen)
+      }
+    }
+    impl SampleRange<$t> for RangeInclusive<$t> {
+      #[inline]
+      fn sample_range<G: Gen32 + ?Sized>(self, gen: &mut G) -> $t {
+        let (low, high) = self.into_inner();
+        assert!(low <= high, "SampleRange::sample_range> Empty range.");
+        let flip = <$via>::MIN;
+        let out = <$range>::new(((low as $via) ^ flip)..=((high as $via) ^ flip)).sample(gen);
+        (out ^ flip) as $t
+      }
+    }
+  };
+}
+impl_sample_range_uint!(u32, RangeI32, i32);
+impl_sample_range_uint!(u64, RangeI64, i64);
+
+impl SampleRange<usize> for Range<usize> {
+  #[inline]
+  fn sample_range<G: Gen32 + ?Sized>(self, gen: &mut G) -> usize {
+    ((self.start as u64)..(self.end as u64)).sample_range(gen) as usize
+  }
+}
+impl SampleRange<usize> for RangeInclusive<usize> {
+  #[inline]
+  fn sample_range<G: Gen32 + ?Sized>(self, gen: &mut G) -> usize {
+    let (low, high) = self.into_inner();
+    ((low as u64)..=(high as u64)).sample_range(gen) as usize
+  }
+}
+
+impl SampleRange<f32> for Range<f32> {
+  #[inline]
+  fn sample_range<G: Gen32 + ?Sized>(self, gen: &mut G) -> f32 {
+    assert!(
+      self.start.is_finite() && self.end.is_finite() && self.start < self.end,
+      "SampleRange::sample_range> Empty range."
+    );
+    loop {
+      // Rounding can sometimes land exactly on the end, so try again then.
+      let x = lerp_f32(self.start, self.end, gen.next_f32_unit());
+      if x < self.end {
+        return x.max(self.start);
+      }
+    }
+  }
+}
+impl SampleRange<f32> for RangeInclusive<f32> {
+  #[inline]
+  fn sample_range<G: Gen32 + ?Sized>(self, gen: &mut G) -> f32 {
+    let (low, high) = self.into_inner();
+    assert!(
+      low.is_finite() && high.is_finite() && low <= high,
+      "SampleRange::sample_range> Empty range."
+    );
+    lerp_f32(low, high, gen.next_f32_unit()).max(low).min(high)
+  }
+}
+
+/// Goes `t` of the way from `a` to `b`, without overflow even if `b - a`
+/// wouldn't fit in an `f32`.
+#[inline]
+fn lerp_f32(a: f32, b: f32, t: f32) -> f32 {
+  a * (1.0 - t) + b * t
+}
+
+/// Multiplies two `u64` values into a `(high, low)` pair of `u64` values.
+#[inline]
+#[cfg(target_pointer_width = "64")]
+const fn mul_wide_u64(a: u64, b: u64) -> (u64, u64) {
+  let mul = (a as u128) * (b as u128);
+  ((mul >> 64) as u64, mul as u64)
+}
+
+/// Multiplies two `u64` values into a `(high, low)` pair of `u64` values.
+#[inline]
+#[cfg(not(target_pointer_width = "64"))]
+const fn mul_wide_u64(a: u64, b: u64) -> (u64, u64) {
+  let (a_lo, a_hi) = (a & 0xFFFF_FFFF, a >> 32);
+  let (b_lo, b_hi) = (b & 0xFFFF_FFFF, b >> 32);
+  let lo_lo = a_lo * b_lo;
+  let hi_lo = a_hi * b_lo;
+  let lo_hi = a_lo * b_hi;
+  let hi_hi = a_hi * b_hi;
+  let cross = (lo_lo >> 32) + (hi_lo & 0xFFFF_FFFF) + lo_hi;
+  let high = hi_hi + (hi_lo >> 32) + (cross >> 32);
+  let low = (cross << 32) | (lo_lo & 0xFFFF_FFFF);
+  (high, low)
+}
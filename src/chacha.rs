@@ -0,0 +1,285 @@
+use super::*;
+
+/// "expand 32-byte k"
+const CHACHA_CONSTANTS: [u32; 4] = [0x61707865, 0x3320646e, 0x79622d32, 0x6b206574];
+
+/// The ChaCha quarter round, on four words of the working state.
+macro_rules! chacha_quarter_round {
+  ($x:ident, $a:expr, $b:expr, $c:expr, $d:expr) => {
+    $x[$a] = $x[$a].wrapping_add($x[$b]);
+    $x[$d] = ($x[$d] ^ $x[$a]).rotate_left(16);
+    $x[$c] = $x[$c].wrapping_add($x[$d]);
+    $x[$b] = ($x[$b] ^ $x[$c]).rotate_left(12);
+    $x[$a] = $x[$a].wrapping_add($x[$b]);
+    $x[$d] = ($x[$d] ^ $x[$a]).rotate_left(8);
+    $x[$c] = $x[$c].wrapping_add($x[$d]);
+    $x[$b] = ($x[$b] ^ $x[$c]).rotate_left(7);
+  };
+}
+
+/// Computes one ChaCha block.
+///
+/// This is the original (djb) layout: a 64-bit block counter in words 12 and
+/// 13, and a 64-bit stream id (nonce) in words 14 and 15.
+fn chacha_block(key: &[u32; 8], block: u64, stream: u64, double_rounds: usize) -> [u32; 16] {
+  let input: [u32; 16] = [
+    CHACHA_CONSTANTS[0],
+    CHACHA_CONSTANTS[1],
+    CHACHA_CONSTANTS[2],
+    CHACHA_CONSTANTS[3],
+    key[0],
+    key[1],
+    key[2],
+    key[3],
+    key[4],
+    key[5],
+    key[6],
+    key[7],
+    block as u32,
+    (block >> 32) as u32,
+    stream as u32,
+    (stream >> 32) as u32,
+  ];
+  let mut x = input;
+  for _ in 0..double_rounds {
+    chacha_quarter_round!(x, 0, 4, 8, 12);
+    chacha_quarter_round!(x, 1, 5, 9, 13);
+    chacha_quarter_round!(x, 2, 6, 10, 14);
+    chacha_quarter_round!(x, 3, 7, 11, 15);
+    chacha_quarter_round!(x, 0, 5, 10, 15);
+    chacha_quarter_round!(x, 1, 6, 11, 12);
+    chacha_quarter_round!(x, 2, 7, 8, 13);
+    chacha_quarter_round!(x, 3, 4, 9, 14);
+  }
+  for (out, i) in x.iter_mut().zip(input.iter()) {
+    *out = out.wrapping_add(*i);
+  }
+  x
+}
+
+/// The state shared by all the ChaCha generators, which differ only in the
+/// number of rounds.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct ChaChaCore {
+  #[cfg_attr(feature = "serde", serde(rename = "key_v1"))]
+  key: [u32; 8],
+  /// The block that will be computed on the next refill.
+  #[cfg_attr(feature = "serde", serde(rename = "next_block_v1"))]
+  next_block: u64,
+  #[cfg_attr(feature = "serde", serde(rename = "stream_v1"))]
+  stream: u64,
+  #[cfg_attr(feature = "serde", serde(rename = "buffer_v1"))]
+  buffer: [u32; 16],
+  /// Index of the next unused word in `buffer`, 16 if it's all used.
+  #[cfg_attr(feature = "serde", serde(rename = "index_v1"))]
+  index: usize,
+}
+
+impl ChaChaCore {
+  const fn new(key: [u32; 8], stream: u64) -> Self {
+    Self { key, next_block: 0, stream, buffer: [0; 16], index: 16 }
+  }
+
+  #[inline]
+  fn next_u32(&mut self, double_rounds: usize) -> u32 {
+    if self.index >= 16 {
+      self.buffer = chacha_block(&self.key, self.next_block, self.stream, double_rounds);
+      self.next_block = self.next_block.wrapping_add(1);
+      self.index = 0;
+    }
+    let out = self.buffer[self.index];
+    self.index += 1;
+    out
+  }
+
+  fn word_pos(&self) -> u128 {
+    if self.index >= 16 {
+      (self.next_block as u128) << 4
+    } else {
+      ((self.next_block.wrapping_sub(1) as u128) << 4) | (self.index as u128)
+    }
+  }
+
+  fn set_word_pos(&mut self, word_pos: u128, double_rounds: usize) {
+    let block = (word_pos >> 4) as u64;
+    let index = (word_pos & 0xF) as usize;
+    if index == 0 {
+      self.next_block = block;
+      self.index = 16;
+    } else {
+      self.buffer = chacha_block(&self.key, block, self.stream, double_rounds);
+      self.next_block = block.wrapping_add(1);
+      self.index = index;
+    }
+  }
+
+  fn set_stream(&mut self, stream: u64, double_rounds: usize) {
+    self.stream = stream;
+    if self.index < 16 {
+      let current = self.next_block.wrapping_sub(1);
+      self.buffer = chacha_block(&self.key, current, self.stream, double_rounds);
+    }
+  }
+}
+
+/// A ChaCha generator with 8 rounds, giving 32 bits of output per step.
+///
+/// * This is seeded with a 256-bit key and a 64-bit stream id. Each (key,
+///   stream) pair gives a separate sequence of `2^64` blocks of 16 outputs.
+/// * Output is computed one 64-byte block at a time and buffered, so most calls
+///   to `next_u32` are just a buffer read.
+/// * This is much slower than [`Pcg32`], but the output is statistically
+///   flawless for any practical purpose.
+/// * Eight rounds is *not* considered cryptographically secure, only
+///   statistically sound.
+/// * The methods on this type are quite minimal. You're expected to use the
+///   [`Gen32`] trait to provide most of the useful operations.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChaCha8 {
+  #[cfg_attr(feature = "serde", serde(rename = "core_v1"))]
+  core: ChaChaCore,
+}
+
+impl ChaCha8 {
+  const DOUBLE_ROUNDS: usize = 4;
+
+  /// Seed a new generator from a key and a stream id.
+  pub const fn seed(key: [u32; 8], stream: u64) -> Self {
+    Self { core: ChaChaCore::new(key, stream) }
+  }
+
+  /// Gets the next 32-bits of output.
+  #[inline]
+  pub fn next_u32(&mut self) -> u32 {
+    self.core.next_u32(Self::DOUBLE_ROUNDS)
+  }
+
+  /// The stream id of this generator.
+  #[inline]
+  pub const fn stream(&self) -> u64 {
+    self.core.stream
+  }
+
+  /// Switches to another stream, keeping the same position within it.
+  #[inline]
+  pub fn set_stream(&mut self, stream: u64) {
+    self.core.set_stream(stream, Self::DOUBLE_ROUNDS)
+  }
+
+  /// The number of `u32` outputs produced so far in the current stream.
+  ///
+  /// Only the low 68 bits are meaningful.
+  #[inline]
+  pub fn word_pos(&self) -> u128 {
+    self.core.word_pos()
+  }
+
+  /// Moves to a given output position within the current stream.
+  ///
+  /// Only the low 68 bits are used.
+  #[inline]
+  pub fn set_word_pos(&mut self, word_pos: u128) {
+    self.core.set_word_pos(word_pos, Self::DOUBLE_ROUNDS)
+  }
+}
+
+impl Default for ChaCha8 {
+  fn default() -> Self {
+    const THE_DEFAULT: ChaCha8 = ChaCha8::seed(
+      [
+        DEFAULT_PCG_SEED as u32,
+        (DEFAULT_PCG_SEED >> 32) as u32,
+        (DEFAULT_PCG_SEED >> 64) as u32,
+        (DEFAULT_PCG_SEED >> 96) as u32,
+        DEFAULT_PCG_INC as u32,
+        (DEFAULT_PCG_INC >> 32) as u32,
+        (DEFAULT_PCG_INC >> 64) as u32,
+        (DEFAULT_PCG_INC >> 96) as u32,
+      ],
+      0,
+    );
+    THE_DEFAULT
+  }
+}
+
+impl Gen32 for ChaCha8 {
+  fn next_u32(&mut self) -> u32 {
+    ChaCha8::next_u32(self)
+  }
+}
+
+/// A ChaCha generator with 20 rounds, giving 32 bits of output per step.
+///
+/// * This works the same as [`ChaCha8`], but with the full 20 rounds of the
+///   ChaCha20 stream cipher. Given a secret, uniformly random key the output is
+///   suitable for cryptographic use.
+/// * The *rest* of this crate is still not for cryptographic purposes: seeding
+///   this from another generator in this crate, or from a guessable value,
+///   gives no security at all. Get the key from a real entropy source, and use
+///   [`reseed`](Self::reseed) to replace it when needed.
+/// * Output positions are fully reproducible with [`stream`](Self::stream) and
+///   [`word_pos`](Self::word_pos), so a position can be saved and restored
+///   without saving the whole generator.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChaCha20Rng {
+  #[cfg_attr(feature = "serde", serde(rename = "core_v1"))]
+  core: ChaChaCore,
+}
+
+impl ChaCha20Rng {
+  const DOUBLE_ROUNDS: usize = 10;
+
+  /// Seed a new generator from a key and a stream id.
+  pub const fn seed(key: [u32; 8], stream: u64) -> Self {
+    Self { core: ChaChaCore::new(key, stream) }
+  }
+
+  /// Replaces the key, and goes back to the start of the current stream.
+  #[inline]
+  pub fn reseed(&mut self, key: [u32; 8]) {
+    self.core = ChaChaCore::new(key, self.core.stream);
+  }
+
+  /// Gets the next 32-bits of output.
+  #[inline]
+  pub fn next_u32(&mut self) -> u32 {
+    self.core.next_u32(Self::DOUBLE_ROUNDS)
+  }
+
+  /// The stream id of this generator.
+  #[inline]
+  pub const fn stream(&self) -> u64 {
+    self.core.stream
+  }
+
+  /// Switches to another stream, keeping the same position within it.
+  #[inline]
+  pub fn set_stream(&mut self, stream: u64) {
+    self.core.set_stream(stream, Self::DOUBLE_ROUNDS)
+  }
+
+  /// The number of `u32` outputs produced so far in the current stream.
+  ///
+  /// Only the low 68 bits are meaningful.
+  #[inline]
+  pub fn word_pos(&self) -> u128 {
+    self.core.word_pos()
+  }
+
+  /// Moves to a given output position within the current stream.
+  ///
+  /// Only the low 68 bits are used.
+  #[inline]
+  pub fn set_word_pos(&mut self, word_pos: u128) {
+    self.core.set_word_pos(word_pos, Self::DOUBLE_ROUNDS)
+  }
+}
+
+impl Gen32 for ChaCha20Rng {
+  fn next_u32(&mut self) -> u32 {
+    ChaCha20Rng::next_u32(self)
+  }
+}
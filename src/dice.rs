@@ -0,0 +1,190 @@
+use super::*;
+use core::convert::TryFrom;
+
+/// Dice rolling helpers, for any [`Gen32`].
+///
+/// * This is implemented for every generator, so just bring the trait into
+///   scope and call `gen.d6()` and so on.
+/// * All dice are numbered from 1 up to the number of sides, and every face is
+///   equally likely (each roll is one [`next_bounded`](Gen32::next_bounded)).
+pub trait Dice: Gen32 {
+  /// Rolls a die with the given number of sides, giving `1 ..= sides`.
+  ///
+  /// ## Panics
+  /// * If `sides` is 0.
+  #[inline]
+  fn roll(&mut self, sides: u32) -> u32 {
+    assert!(sides != 0, "Dice::roll> A die must have at least one side.");
+    self.next_bounded(sides) + 1
+  }
+
+  /// Rolls a d4.
+  #[inline]
+  fn d4(&mut self) -> u32 {
+    self.roll(4)
+  }
+
+  /// Rolls a d6.
+  #[inline]
+  fn d6(&mut self) -> u32 {
+    self.roll(6)
+  }
+
+  /// Rolls a d8.
+  #[inline]
+  fn d8(&mut self) -> u32 {
+    self.roll(8)
+  }
+
+  /// Rolls a d10 (numbered 1 to 10).
+  #[inline]
+  fn d10(&mut self) -> u32 {
+    self.roll(10)
+  }
+
+  /// Rolls a d12.
+  #[inline]
+  fn d12(&mut self) -> u32 {
+    self.roll(12)
+  }
+
+  /// Rolls a d20.
+  #[inline]
+  fn d20(&mut self) -> u32 {
+    self.roll(20)
+  }
+
+  /// Rolls a d100 (numbered 1 to 100).
+  #[inline]
+  fn d100(&mut self) -> u32 {
+    self.roll(100)
+  }
+
+  /// Rolls `n` dice with the given number of sides, and adds them up along
+  /// with the modifier. For example, "3d6+2" is `roll_sum(3, 6, 2)`.
+  ///
+  /// * The total saturates instead of overflowing.
+  ///
+  /// ## Panics
+  /// * If `sides` is 0.
+  #[inline]
+  fn roll_sum(&mut self, n: u32, sides: u32, modifier: i32) -> i32 {
+    (0..n).fold(modifier, |total, _| {
+      total.saturating_add(i32::try_from(self.roll(sides)).unwrap_or(i32::MAX))
+    })
+  }
+
+  /// Rolls a die twice and keeps the higher result ("advantage").
+  ///
+  /// ## Panics
+  /// * If `sides` is 0.
+  #[inline]
+  fn roll_advantage(&mut self, sides: u32) -> u32 {
+    let a = self.roll(sides);
+    let b = self.roll(sides);
+    a.max(b)
+  }
+
+  /// Rolls a die twice and keeps the lower result ("disadvantage").
+  ///
+  /// ## Panics
+  /// * If `sides` is 0.
+  #[inline]
+  fn roll_disadvantage(&mut self, sides: u32) -> u32 {
+    let a = self.roll(sides);
+    let b = self.roll(sides);
+    a.min(b)
+  }
+
+  /// Rolls an "exploding" die: whenever it shows its highest face, it's rolled
+  /// again and the new roll is added on.
+  ///
+  /// * At most `max_explosions` extra rolls are made, so a d1 (or a very lucky
+  ///   streak) can't loop forever.
+  /// * The total saturates instead of overflowing.
+  ///
+  /// ## Panics
+  /// * If `sides` is 0.
+  #[inline]
+  fn roll_exploding(&mut self, sides: u32, max_explosions: u32) -> u32 {
+    let mut r = self.roll(sides);
+    let mut total = r;
+    let mut explosions = 0;
+    while r == sides && explosions < max_explosions {
+      r = self.roll(sides);
+      total = total.saturating_add(r);
+      explosions += 1;
+    }
+    total
+  }
+
+  /// Rolls a die, rerolling for as long as the result is below `threshold`.
+  ///
+  /// * This is done as a single bounded draw over `threshold ..= sides`, which
+  ///   is the same distribution as actually rerolling, but never loops.
+  /// * A `threshold` of 0 or 1 is a normal roll.
+  ///
+  /// ## Panics
+  /// * If `sides` is 0.
+  /// * If `threshold` is more than `sides`.
+  #[inline]
+  fn roll_reroll_below(&mut self, sides: u32, threshold: u32) -> u32 {
+    assert!(sides != 0, "Dice::roll_reroll_below> A die must have at least one side.");
+    assert!(
+      threshold <= sides,
+      "Dice::roll_reroll_below> The threshold can't be more than the number of sides."
+    );
+    let low = threshold.max(1);
+    low + self.next_bounded(sides - low + 1)
+  }
+
+  /// Rolls a die, and if the result is below `threshold` rerolls it *once*,
+  /// keeping the second result even if it's also low.
+  ///
+  /// ## Panics
+  /// * If `sides` is 0.
+  #[inline]
+  fn roll_reroll_once_below(&mut self, sides: u32, threshold: u32) -> u32 {
+    let r = self.roll(sides);
+    if r < threshold {
+      self.roll(sides)
+    } else {
+      r
+    }
+  }
+
+  /// Rolls `N` dice and adds up the `k` highest. For example, "4d6 drop
+  /// lowest" is `roll_keep_highest::<4>(6, 3)`.
+  ///
+  /// * The dice are rolled into a stack array, so `N` should stay small.
+  ///
+  /// ## Panics
+  /// * If `sides` is 0.
+  /// * If `k` is more than `N`.
+  #[inline]
+  fn roll_keep_highest<const N: usize>(&mut self, sides: u32, k: usize) -> u32 {
+    assert!(k <= N, "Dice::roll_keep_highest> Can't keep more dice than are rolled.");
+    let mut rolls = [0_u32; N];
+    rolls.iter_mut().for_each(|r| *r = self.roll(sides));
+    rolls.sort_unstable();
+    rolls[N - k..].iter().fold(0_u32, |total, r| total.saturating_add(*r))
+  }
+
+  /// Rolls `N` dice and adds up the `k` lowest.
+  ///
+  /// * The dice are rolled into a stack array, so `N` should stay small.
+  ///
+  /// ## Panics
+  /// * If `sides` is 0.
+  /// * If `k` is more than `N`.
+  #[inline]
+  fn roll_keep_lowest<const N: usize>(&mut self, sides: u32, k: usize) -> u32 {
+    assert!(k <= N, "Dice::roll_keep_lowest> Can't keep more dice than are rolled.");
+    let mut rolls = [0_u32; N];
+    rolls.iter_mut().for_each(|r| *r = self.roll(sides));
+    rolls.sort_unstable();
+    rolls[..k].iter().fold(0_u32, |total, r| total.saturating_add(*r))
+  }
+}
+
+impl<G: Gen32 + ?Sized> Dice for G {}
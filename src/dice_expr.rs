@@ -0,0 +1,208 @@
+use super::*;
+use core::convert::TryFrom;
+
+/// One `NdS` group of dice in a [`DiceExpr`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+struct DiceTerm {
+  count: u32,
+  sides: u32,
+  negative: bool,
+}
+
+/// A dice expression in standard notation, such as `"3d6+2"` or
+/// `"2d8+1d4-1"`, that can be rolled many times.
+///
+/// * An expression is any number of terms joined by `+` or `-`. Each term is
+///   either dice (`NdS`, with `N` defaulting to 1, so `"d20"` works) or a plain
+///   number. Spaces are ignored, and the `d` can be upper or lower case.
+/// * The parsed form holds up to `N` dice terms (plain numbers are all folded
+///   into one modifier and don't count), so parsing needs no allocation.
+/// * Each die is rolled with [`Dice::roll`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DiceExpr<const N: usize = 8> {
+  terms: [DiceTerm; N],
+  len: usize,
+  modifier: i32,
+}
+
+impl<const N: usize> DiceExpr<N> {
+  /// Parses an expression.
+  ///
+  /// ## Failure
+  /// * If the text isn't a valid expression.
+  /// * If a die has 0 sides.
+  /// * If there are more than `N` dice terms.
+  /// * If a number doesn't fit in a `u32`, or the modifier doesn't fit in an
+  ///   `i32`.
+  pub fn parse(text: &str) -> Option<Self> {
+    let mut out = Self { terms: [DiceTerm::default(); N], len: 0, modifier: 0 };
+    let mut bytes = text.bytes().filter(|b| !b.is_ascii_whitespace()).peekable();
+    let mut negative = match bytes.peek() {
+      Some(b'-') => {
+        bytes.next();
+        true
+      }
+      Some(b'+') => {
+        bytes.next();
+        false
+      }
+      Some(_) => false,
+      None => return None,
+    };
+    loop {
+      let count = parse_u32(&mut bytes)?;
+      if let Some(b'd') | Some(b'D') = bytes.peek() {
+        bytes.next();
+        let sides = parse_u32(&mut bytes)??;
+        if sides == 0 || out.len == N {
+          return None;
+        }
+        out.terms[out.len] = DiceTerm { count: count.unwrap_or(1), sides, negative };
+        out.len += 1;
+      } else {
+        let value = i32::try_from(count?).ok()?;
+        let value = if negative { -value } else { value };
+        out.modifier = out.modifier.checked_add(value)?;
+      }
+      negative = match bytes.next() {
+        Some(b'+') => false,
+        Some(b'-') => true,
+        Some(_) => return None,
+        None => return Some(out),
+      };
+    }
+  }
+
+  /// The total number of dice rolled each time.
+  #[inline]
+  pub fn dice_count(&self) -> u32 {
+    self.terms().iter().fold(0_u32, |n, t| n.saturating_add(t.count))
+  }
+
+  /// The sum of all the plain numbers in the expression.
+  #[inline]
+  pub const fn modifier(&self) -> i32 {
+    self.modifier
+  }
+
+  /// The lowest possible total.
+  #[inline]
+  pub fn min(&self) -> i32 {
+    self.terms().iter().fold(self.modifier, |total, t| {
+      let low = if t.negative { t.count.saturating_mul(t.sides) } else { t.count };
+      add_signed(total, low, t.negative)
+    })
+  }
+
+  /// The highest possible total.
+  #[inline]
+  pub fn max(&self) -> i32 {
+    self.terms().iter().fold(self.modifier, |total, t| {
+      let high = if t.negative { t.count } else { t.count.saturating_mul(t.sides) };
+      add_signed(total, high, t.negative)
+    })
+  }
+
+  /// Rolls the expression, giving the total.
+  ///
+  /// * The total saturates instead of overflowing.
+  #[inline]
+  pub fn roll<G: Gen32 + ?Sized>(&self, gen: &mut G) -> i32 {
+    self.terms().iter().fold(self.modifier, |total, t| {
+      (0..t.count).fold(total, |total, _| add_signed(total, gen.roll(t.sides), t.negative))
+    })
+  }
+
+  /// Rolls the expression, giving the total and writing each die's result
+  /// into `results` (in the order the dice appear in the expression).
+  ///
+  /// * Results are the face shown on each die, even for subtracted dice.
+  /// * The total saturates instead of overflowing.
+  ///
+  /// ## Panics
+  /// * If `results` is shorter than [`dice_count`](Self::dice_count).
+  #[inline]
+  pub fn roll_into<G: Gen32 + ?Sized>(&self, gen: &mut G, results: &mut [u32]) -> i32 {
+    assert!(
+      results.len() >= self.dice_count() as usize,
+      "DiceExpr::roll_into> The results buffer is too short."
+    );
+    let mut results = results.iter_mut();
+    self.terms().iter().fold(self.modifier, |total, t| {
+      (0..t.count).fold(total, |total, _| {
+        let r = gen.roll(t.sides);
+        *results.next().unwrap() = r;
+        add_signed(total, r, t.negative)
+      })
+    })
+  }
+
+  #[inline]
+  fn terms(&self) -> &[DiceTerm] {
+    &self.terms[..self.len]
+  }
+}
+
+/// Adds or subtracts a `u32` from an `i32`, saturating.
+#[inline]
+fn add_signed(total: i32, value: u32, negative: bool) -> i32 {
+  let value = i32::try_from(value).unwrap_or(i32::MAX);
+  if negative {
+    total.saturating_sub(value)
+  } else {
+    total.saturating_add(value)
+  }
+}
+
+/// Parses the digits at the front of the bytes.
+///
+/// Gives `Some(None)` if there are no digits, and `None` if the number doesn't
+/// fit in a `u32`.
+fn parse_u32(bytes: &mut core::iter::Peekable<impl Iterator<Item = u8>>) -> Option<Option<u32>> {
+  let mut out: Option<u32> = None;
+  while let Some(b) = bytes.peek().copied().filter(u8::is_ascii_digit) {
+    bytes.next();
+    let digit = (b - b'0') as u32;
+    out = Some(out.unwrap_or(0).checked_mul(10)?.checked_add(digit)?);
+  }
+  Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parse_valid() {
+    let e = DiceExpr::<8>::parse("3d6+2").unwrap();
+    assert_eq!((e.dice_count(), e.modifier(), e.min(), e.max()), (3, 2, 5, 20));
+    let e = DiceExpr::<8>::parse("d20").unwrap();
+    assert_eq!((e.dice_count(), e.min(), e.max()), (1, 1, 20));
+    let e = DiceExpr::<8>::parse(" 2D8 - 1d4 - 1 ").unwrap();
+    assert_eq!((e.dice_count(), e.modifier(), e.min(), e.max()), (3, -1, -3, 14));
+    let e = DiceExpr::<8>::parse("-5").unwrap();
+    assert_eq!((e.dice_count(), e.min(), e.max()), (0, -5, -5));
+  }
+
+  #[test]
+  fn parse_invalid() {
+    for &bad in ["", "3d6+", "+", "3d", "d", "3d0", "2x6", "3d6++2", "1d6 1d6"].iter() {
+      assert!(DiceExpr::<8>::parse(bad).is_none(), "{}", bad);
+    }
+  }
+
+  #[test]
+  fn parse_overflow() {
+    assert!(DiceExpr::<8>::parse("4294967296d6").is_none());
+    assert!(DiceExpr::<8>::parse("1d4294967296").is_none());
+    assert!(DiceExpr::<8>::parse("2147483648").is_none());
+    assert!(DiceExpr::<8>::parse("4294967295d6").is_some());
+  }
+
+  #[test]
+  fn parse_too_many_terms() {
+    assert!(DiceExpr::<2>::parse("1d4+1d6").is_some());
+    assert!(DiceExpr::<2>::parse("1d4+1d6+1d8").is_none());
+    assert!(DiceExpr::<2>::parse("1d4+1d6+3").is_some());
+  }
+}
@@ -0,0 +1,177 @@
+//! Non-uniform distributions, all sampled through [`Gen32`].
+//!
+//! Each distribution type checks its parameters once when it's made, and then
+//! has a `sample` method that takes any `Gen32`. Float math is done with
+//! `libm`, so everything here works in `no_std`.
+
+use super::*;
+
+mod ziggurat_tables;
+use ziggurat_tables::*;
+
+mod bernoulli;
+pub use bernoulli::*;
+
+mod beta;
+pub use beta::*;
+
+mod binomial;
+pub use binomial::*;
+
+mod cauchy;
+pub use cauchy::*;
+
+mod categorical;
+pub use categorical::*;
+
+mod dirichlet;
+pub use dirichlet::*;
+
+mod dynamic_weighted_index;
+pub use dynamic_weighted_index::*;
+
+mod exponential;
+pub use exponential::*;
+
+mod gamma;
+pub use gamma::*;
+
+mod geometric;
+pub use geometric::*;
+
+mod gumbel;
+pub use gumbel::*;
+
+mod hypergeometric;
+pub use hypergeometric::*;
+
+mod kumaraswamy;
+pub use kumaraswamy::*;
+
+mod laplace;
+pub use laplace::*;
+
+mod log_normal;
+pub use log_normal::*;
+
+mod logistic;
+pub use logistic::*;
+
+mod multinomial;
+pub use multinomial::*;
+
+mod negative_binomial;
+pub use negative_binomial::*;
+
+mod normal;
+pub use normal::*;
+
+mod pareto;
+pub use pareto::*;
+
+mod poisson;
+pub use poisson::*;
+
+mod rayleigh;
+pub use rayleigh::*;
+
+mod student_t;
+pub use student_t::*;
+
+mod triangular;
+pub use triangular::*;
+
+mod truncated_normal;
+pub use truncated_normal::*;
+
+mod von_mises;
+pub use von_mises::*;
+
+mod weibull;
+pub use weibull::*;
+
+mod weighted_alias;
+pub use weighted_alias::*;
+
+mod zipf;
+pub use zipf::*;
+
+/// Gets an `f64` in the open interval `0.0 .. 1.0`, never exactly either end.
+///
+/// This is what you want before taking a `ln` or dividing.
+#[inline]
+pub(crate) fn f64_open01<G: Gen32 + ?Sized>(gen: &mut G) -> f64 {
+  ((gen.next_u64() >> 12) as f64 + 0.5) * (1.0 / (1_u64 << 52) as f64)
+}
+
+/// Samples from a 256 layer ziggurat.
+///
+/// * `x_tab` and `f_tab` are the layer edges and pdf values at those edges.
+/// * If `symmetric` the output can be negative, otherwise it's non-negative.
+/// * `pdf` is the unnormalized pdf, and `zero_case` samples the tail beyond the
+///   base layer (it's given the uniform value that picked the tail).
+#[inline]
+fn ziggurat<G: Gen32 + ?Sized>(
+  gen: &mut G, symmetric: bool, x_tab: &[f64; 257], f_tab: &[f64; 257], pdf: impl Fn(f64) -> f64,
+  zero_case: impl Fn(&mut G, f64) -> f64,
+) -> f64 {
+  loop {
+    let bits = gen.next_u64();
+    let i = (bits & 0xFF) as usize;
+    // 52 random bits as the mantissa of a float in `1.0 .. 2.0`.
+    let one_two = f64::from_bits((bits >> 12) | 0x3FF0_0000_0000_0000);
+    let u = if symmetric { 2.0 * one_two - 3.0 } else { one_two - 1.0 };
+    let x = u * x_tab[i];
+    let test_x = if symmetric { libm::fabs(x) } else { x };
+    if test_x < x_tab[i + 1] {
+      return x;
+    }
+    if i == 0 {
+      return zero_case(gen, u);
+    }
+    if f_tab[i + 1] + (f_tab[i] - f_tab[i + 1]) * gen.next_f64_unit() < pdf(x) {
+      return x;
+    }
+  }
+}
+
+/// Samples the standard normal distribution (mean 0, standard deviation 1).
+#[inline]
+pub(crate) fn standard_normal<G: Gen32 + ?Sized>(gen: &mut G) -> f64 {
+  ziggurat(
+    gen,
+    true,
+    &ZIG_NORM_X,
+    &ZIG_NORM_F,
+    |x| libm::exp(-x * x / 2.0),
+    |gen, u| {
+      // Marsaglia's method for the tail beyond `R`.
+      let mut x = 1.0_f64;
+      let mut y = 0.0_f64;
+      while -2.0 * y < x * x {
+        x = libm::log(f64_open01(gen)) / ZIG_NORM_R;
+        y = libm::log(f64_open01(gen));
+      }
+      if u < 0.0 {
+        x - ZIG_NORM_R
+      } else {
+        ZIG_NORM_R - x
+      }
+    },
+  )
+}
+
+/// Samples the standard exponential distribution (rate 1).
+#[inline]
+pub(crate) fn standard_exp<G: Gen32 + ?Sized>(gen: &mut G) -> f64 {
+  ziggurat(
+    gen,
+    false,
+    &ZIG_EXP_X,
+    &ZIG_EXP_F,
+    |x| libm::exp(-x),
+    // The exponential is memoryless, so the tail is just another exponential
+    // shifted past `R`.
+    |gen, _u| ZIG_EXP_R - libm::log(f64_open01(gen)),
+  )
+}
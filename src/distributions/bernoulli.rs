@@ -0,0 +1,104 @@
+use super::*;
+
+/// The Bernoulli distribution, `true` with probability `p`.
+///
+/// * The probability is turned into a `u64` threshold when this is made, so
+///   each sample is a single `next_u64` and a compare, with no float math.
+/// * Probabilities are rounded to the nearest multiple of `2^-64`, except that
+///   exactly 1 is kept exact (it always gives `true`).
+/// * [`from_ratio`](Self::from_ratio) makes the threshold with integer division
+///   only, so it also works with no float math at all.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bernoulli {
+  /// A `u64` under this is a `true` result, unless this is `ALWAYS_TRUE`.
+  threshold: u64,
+}
+
+impl Bernoulli {
+  /// Stands in for a threshold of `2^64`, which doesn't fit in a `u64`.
+  const ALWAYS_TRUE: u64 = u64::MAX;
+
+  /// Constructs a new `Bernoulli`.
+  ///
+  /// ## Panics
+  /// * If `p` isn't in the range `0.0 ..= 1.0`.
+  #[inline]
+  pub fn new(p: f64) -> Self {
+    Self::try_new(p).expect("Bernoulli::new> Invalid parameters.")
+  }
+
+  /// Constructs a new `Bernoulli`, or `None` on failure.
+  ///
+  /// ## Failure
+  /// * If `p` isn't in the range `0.0 ..= 1.0`.
+  #[inline]
+  pub fn try_new(p: f64) -> Option<Self> {
+    if !(0.0..=1.0).contains(&p) {
+      None
+    } else if p == 1.0 {
+      Some(Self { threshold: Self::ALWAYS_TRUE })
+    } else {
+      // Values that round up to `2^64` are kept just under 1.
+      let threshold = (p * 18446744073709551616.0) as u64;
+      Some(Self { threshold: threshold.min(Self::ALWAYS_TRUE - 1) })
+    }
+  }
+
+  /// Constructs a new `Bernoulli` from an `f32` probability.
+  ///
+  /// ## Panics
+  /// * If `p` isn't in the range `0.0 ..= 1.0`.
+  #[inline]
+  pub fn from_f32(p: f32) -> Self {
+    Self::new(p as f64)
+  }
+
+  /// Constructs a new `Bernoulli` that's `true` `numerator` out of every
+  /// `denominator` times.
+  ///
+  /// ## Panics
+  /// * If the denominator is 0, or the numerator is bigger than the
+  ///   denominator.
+  #[inline]
+  pub const fn from_ratio(numerator: u32, denominator: u32) -> Self {
+    match Self::try_from_ratio(numerator, denominator) {
+      Some(b) => b,
+      None => panic!("Bernoulli::from_ratio> Invalid ratio."),
+    }
+  }
+
+  /// Constructs a new `Bernoulli` from a ratio, or `None` on failure.
+  ///
+  /// ## Failure
+  /// * If the denominator is 0, or the numerator is bigger than the
+  ///   denominator.
+  #[inline]
+  pub const fn try_from_ratio(numerator: u32, denominator: u32) -> Option<Self> {
+    if denominator == 0 || numerator > denominator {
+      None
+    } else if numerator == denominator {
+      Some(Self { threshold: Self::ALWAYS_TRUE })
+    } else {
+      let threshold = ((numerator as u128) << 64) / (denominator as u128);
+      Some(Self { threshold: threshold as u64 })
+    }
+  }
+
+  /// The probability of a `true` result (after rounding).
+  #[inline]
+  pub fn p(self) -> f64 {
+    if self.threshold == Self::ALWAYS_TRUE {
+      1.0
+    } else {
+      self.threshold as f64 / 18446744073709551616.0
+    }
+  }
+
+  /// Samples a value.
+  #[inline]
+  pub fn sample<G: Gen32 + ?Sized>(self, gen: &mut G) -> bool {
+    let x = gen.next_u64();
+    // With `ALWAYS_TRUE` the compare only fails for an `x` of `u64::MAX`.
+    x < self.threshold || self.threshold == Self::ALWAYS_TRUE
+  }
+}
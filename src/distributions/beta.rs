@@ -0,0 +1,150 @@
+use super::*;
+
+/// `ln(4)`
+const LN_4: f64 = 1.3862943611198906;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BetaMethod {
+  /// Cheng's algorithm BB, for when both parameters are over 1.
+  Bb { alpha: f64, beta: f64, gamma: f64 },
+  /// Cheng's algorithm BC, for when either parameter is at most 1.
+  Bc { alpha: f64, beta: f64, kappa1: f64, kappa2: f64 },
+}
+
+/// The beta distribution, over `0.0 ..= 1.0`, with shape parameters `alpha`
+/// and `beta`.
+///
+/// * Good for modeling bounded quantities that cluster somewhere, like a hit
+///   rate. The mean is `alpha / (alpha + beta)`, and larger parameters give a
+///   tighter spread.
+/// * Sampling uses R. C. H. Cheng's rejection algorithms from "Generating beta
+///   variates with nonintegral shape parameters" (1978), which take about 2
+///   uniform values per sample.
+/// * See [`Kumaraswamy`](crate::Kumaraswamy) for a much cheaper distribution
+///   with a similar shape.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Beta {
+  alpha: f64,
+  beta: f64,
+  /// The parameters in the order the chosen algorithm wants them.
+  a: f64,
+  b: f64,
+  /// If `a` and `b` are swapped from `alpha` and `beta`.
+  switched: bool,
+  method: BetaMethod,
+}
+
+impl Beta {
+  /// Constructs a new `Beta`.
+  ///
+  /// ## Panics
+  /// * If either parameter isn't finite, or isn't positive.
+  #[inline]
+  pub fn new(alpha: f64, beta: f64) -> Self {
+    Self::try_new(alpha, beta).expect("Beta::new> Invalid parameters.")
+  }
+
+  /// Constructs a new `Beta`, or `None` on failure.
+  ///
+  /// ## Failure
+  /// * If either parameter isn't finite, or isn't positive.
+  #[inline]
+  pub fn try_new(alpha: f64, beta: f64) -> Option<Self> {
+    if !(alpha.is_finite() && alpha > 0.0 && beta.is_finite() && beta > 0.0) {
+      return None;
+    }
+    // `a` is the smaller parameter.
+    let (a, b, switched) = if alpha < beta { (alpha, beta, false) } else { (beta, alpha, true) };
+    if a > 1.0 {
+      let sum = a + b;
+      let beta_ = libm::sqrt((sum - 2.0) / (2.0 * a * b - sum));
+      let method = BetaMethod::Bb { alpha: sum, beta: beta_, gamma: a + 1.0 / beta_ };
+      Some(Self { alpha, beta, a, b, switched, method })
+    } else {
+      // For BC, `a` is the larger parameter instead.
+      let (a, b, switched) = (b, a, !switched);
+      let sum = a + b;
+      let beta_ = 1.0 / b;
+      let delta = 1.0 + a - b;
+      let kappa1 = delta * (1.0 / 72.0 + b / 24.0) / (a * beta_ - 7.0 / 9.0);
+      let kappa2 = 0.25 + (0.5 + 0.25 / delta) * b;
+      let method = BetaMethod::Bc { alpha: sum, beta: beta_, kappa1, kappa2 };
+      Some(Self { alpha, beta, a, b, switched, method })
+    }
+  }
+
+  /// The first shape parameter.
+  #[inline]
+  pub const fn alpha(self) -> f64 {
+    self.alpha
+  }
+
+  /// The second shape parameter.
+  #[inline]
+  pub const fn beta(self) -> f64 {
+    self.beta
+  }
+
+  /// Samples a value.
+  #[inline]
+  pub fn sample<G: Gen32 + ?Sized>(self, gen: &mut G) -> f64 {
+    let (a, b) = (self.a, self.b);
+    // Gets `v` and `w` from `u1`, keeping `w` finite.
+    let vw = |beta: f64, u1: f64| {
+      let v = beta * libm::log(u1 / (1.0 - u1));
+      let w = a * libm::exp(v);
+      (v, if w.is_finite() { w } else { f64::MAX })
+    };
+    let w = match self.method {
+      BetaMethod::Bb { alpha, beta, gamma } => loop {
+        let u1 = f64_open01(gen);
+        let u2 = f64_open01(gen);
+        let (v, w) = vw(beta, u1);
+        let z = u1 * u1 * u2;
+        let r = gamma * v - LN_4;
+        let s = a + r - w;
+        if s + 2.609438 >= 5.0 * z {
+          break w;
+        }
+        let t = libm::log(z);
+        if s > t {
+          break w;
+        }
+        if r + alpha * libm::log(alpha / (b + w)) >= t {
+          break w;
+        }
+      },
+      BetaMethod::Bc { alpha, beta, kappa1, kappa2 } => loop {
+        let u1 = f64_open01(gen);
+        let u2 = f64_open01(gen);
+        let z;
+        if u1 < 0.5 {
+          let y = u1 * u2;
+          z = u1 * y;
+          if 0.25 * u2 + z - y >= kappa1 {
+            continue;
+          }
+        } else {
+          z = u1 * u1 * u2;
+          if z <= 0.25 {
+            break vw(beta, u1).1;
+          }
+          if z >= kappa2 {
+            continue;
+          }
+        }
+        let (v, w) = vw(beta, u1);
+        if alpha * (libm::log(alpha / (b + w)) + v) - LN_4 >= libm::log(z) {
+          break w;
+        }
+      },
+    };
+    if self.switched {
+      b / (b + w)
+    } else if w == f64::MAX {
+      1.0
+    } else {
+      w / (b + w)
+    }
+  }
+}
@@ -0,0 +1,124 @@
+use super::*;
+
+/// Picks an index with a chance given by a list of probabilities.
+///
+/// * The probabilities are checked and normalized once, when this is made, and
+///   turned into a cumulative table of `u64` values. Each sample is then one
+///   `next_u32` and a binary search of the table, so `O(log n)`.
+/// * The table can live in an array that this owns (see
+///   [`new`](Categorical::new)), or in a buffer you provide (see
+///   [`new_in`](Categorical::new_in)). Neither needs allocation.
+/// * Each probability is rounded to a multiple of `2^-32`. A category with a
+///   probability of 0 is never picked.
+/// * See [`WeightedAlias`](crate::WeightedAlias) for `O(1)` sampling, at the
+///   cost of a bigger table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Categorical<B> {
+  /// `cdf[i]` is the (exclusive) upper end of the `u32` values that give `i`.
+  cdf: B,
+}
+
+impl<const N: usize> Categorical<[u64; N]> {
+  /// Constructs a new `Categorical`, with the table in an array.
+  ///
+  /// ## Panics
+  /// * See [`try_new`](Self::try_new).
+  #[inline]
+  pub fn new(probs: &[f32; N]) -> Self {
+    Self::try_new(probs).expect("Categorical::new> Invalid probabilities.")
+  }
+
+  /// Constructs a new `Categorical` with the table in an array, or `None` on
+  /// failure.
+  ///
+  /// ## Failure
+  /// * If any probability isn't finite, or is negative.
+  /// * If the probabilities add up to 0 (including if there are none).
+  #[inline]
+  pub fn try_new(probs: &[f32; N]) -> Option<Self> {
+    let mut cdf = [0_u64; N];
+    if fill_cdf(probs, &mut cdf) {
+      Some(Self { cdf })
+    } else {
+      None
+    }
+  }
+}
+
+impl<'a> Categorical<&'a mut [u64]> {
+  /// Constructs a new `Categorical`, with the table in the buffer given.
+  ///
+  /// ## Panics
+  /// * See [`try_new_in`](Self::try_new_in).
+  #[inline]
+  pub fn new_in(probs: &[f32], buf: &'a mut [u64]) -> Self {
+    Self::try_new_in(probs, buf).expect("Categorical::new_in> Invalid probabilities.")
+  }
+
+  /// Constructs a new `Categorical` with the table in the buffer given, or
+  /// `None` on failure.
+  ///
+  /// ## Failure
+  /// * If the buffer isn't the same length as `probs`.
+  /// * If any probability isn't finite, or is negative.
+  /// * If the probabilities add up to 0 (including if there are none).
+  #[inline]
+  pub fn try_new_in(probs: &[f32], buf: &'a mut [u64]) -> Option<Self> {
+    if probs.len() == buf.len() && fill_cdf(probs, buf) {
+      Some(Self { cdf: buf })
+    } else {
+      None
+    }
+  }
+}
+
+impl<B: AsRef<[u64]>> Categorical<B> {
+  /// The number of categories.
+  #[inline]
+  pub fn len(&self) -> usize {
+    self.cdf.as_ref().len()
+  }
+
+  /// If there are no categories (which is never the case).
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.cdf.as_ref().is_empty()
+  }
+
+  /// The (normalized and rounded) probability of picking an index.
+  ///
+  /// ## Panics
+  /// * If the index is out of bounds.
+  #[inline]
+  pub fn probability(&self, index: usize) -> f64 {
+    let cdf = self.cdf.as_ref();
+    let low = if index == 0 { 0 } else { cdf[index - 1] };
+    (cdf[index] - low) as f64 / 4294967296.0
+  }
+
+  /// Samples an index.
+  #[inline]
+  pub fn sample<G: Gen32 + ?Sized>(&self, gen: &mut G) -> usize {
+    let x = gen.next_u32() as u64;
+    self.cdf.as_ref().partition_point(|&c| c <= x)
+  }
+}
+
+/// Fills `cdf` with the cumulative table for `probs`, which must be the same
+/// length, or gives `false` if the probabilities are invalid.
+fn fill_cdf(probs: &[f32], cdf: &mut [u64]) -> bool {
+  if !probs.iter().all(|p| p.is_finite() && *p >= 0.0) {
+    return false;
+  }
+  let total: f64 = probs.iter().map(|p| *p as f64).sum();
+  if total <= 0.0 {
+    return false;
+  }
+  let last = probs.iter().rposition(|p| *p > 0.0).unwrap();
+  let mut running = 0.0;
+  for (i, (p, c)) in probs.iter().zip(cdf.iter_mut()).enumerate() {
+    running += *p as f64;
+    *c = if i >= last { 1 << 32 } else { libm::round(running / total * 4294967296.0) as u64 };
+  }
+  true
+}
@@ -0,0 +1,60 @@
+use super::*;
+
+/// The Cauchy distribution, a bell shape with very heavy tails.
+///
+/// * Values far from the median are much more common than with a
+///   [`Normal`](crate::Normal). The distribution has no mean or variance at
+///   all, so averaging samples never settles down.
+/// * `scale` is the half-width at half-maximum of the pdf.
+/// * Sampling takes one uniform value and a `tan`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cauchy {
+  median: f64,
+  scale: f64,
+}
+
+impl Cauchy {
+  /// Constructs a new `Cauchy`.
+  ///
+  /// ## Panics
+  /// * If the median isn't finite.
+  /// * If the scale isn't finite, or isn't positive.
+  #[inline]
+  pub fn new(median: f64, scale: f64) -> Self {
+    Self::try_new(median, scale).expect("Cauchy::new> Invalid parameters.")
+  }
+
+  /// Constructs a new `Cauchy`, or `None` on failure.
+  ///
+  /// ## Failure
+  /// * If the median isn't finite.
+  /// * If the scale isn't finite, or isn't positive.
+  #[inline]
+  pub fn try_new(median: f64, scale: f64) -> Option<Self> {
+    if median.is_finite() && scale.is_finite() && scale > 0.0 {
+      Some(Self { median, scale })
+    } else {
+      None
+    }
+  }
+
+  /// The median.
+  #[inline]
+  pub const fn median(self) -> f64 {
+    self.median
+  }
+
+  /// The scale.
+  #[inline]
+  pub const fn scale(self) -> f64 {
+    self.scale
+  }
+
+  /// Samples a value.
+  #[inline]
+  pub fn sample<G: Gen32 + ?Sized>(self, gen: &mut G) -> f64 {
+    // An open interval keeps us off the poles of `tan` at `±pi/2`.
+    let u = f64_open01(gen);
+    self.median + self.scale * libm::tan(core::f64::consts::PI * (u - 0.5))
+  }
+}
@@ -0,0 +1,74 @@
+use super::*;
+
+/// The Dirichlet distribution, which gives random probability vectors.
+///
+/// * Each output is a list of non-negative values that sum to 1, one for each
+///   concentration parameter in `alpha`. The expected value of each entry is
+///   its `alpha` divided by the sum of all the `alpha` values.
+/// * Large `alpha` values give outputs close to that expected value, and small
+///   ones (under 1) give outputs where a few entries take most of the weight.
+/// * This borrows the parameters, and writes samples into a slice you provide,
+///   so no allocation is needed.
+/// * Sampling draws one [`Gamma`] value per entry and then normalizes. With
+///   extremely small `alpha` values every gamma draw can round to 0, in which
+///   case the draws are just done again.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dirichlet<'a> {
+  alpha: &'a [f64],
+}
+
+impl<'a> Dirichlet<'a> {
+  /// Constructs a new `Dirichlet`.
+  ///
+  /// ## Panics
+  /// * If there are fewer than 2 parameters.
+  /// * If any parameter isn't finite, or isn't positive.
+  #[inline]
+  pub fn new(alpha: &'a [f64]) -> Self {
+    Self::try_new(alpha).expect("Dirichlet::new> Invalid parameters.")
+  }
+
+  /// Constructs a new `Dirichlet`, or `None` on failure.
+  ///
+  /// ## Failure
+  /// * If there are fewer than 2 parameters.
+  /// * If any parameter isn't finite, or isn't positive.
+  #[inline]
+  pub fn try_new(alpha: &'a [f64]) -> Option<Self> {
+    if alpha.len() >= 2 && alpha.iter().all(|a| a.is_finite() && *a > 0.0) {
+      Some(Self { alpha })
+    } else {
+      None
+    }
+  }
+
+  /// The concentration parameters.
+  #[inline]
+  pub const fn alpha(self) -> &'a [f64] {
+    self.alpha
+  }
+
+  /// Samples a probability vector into `out`.
+  ///
+  /// ## Panics
+  /// * If `out` isn't the same length as `alpha`.
+  #[inline]
+  pub fn sample_into<G: Gen32 + ?Sized>(self, gen: &mut G, out: &mut [f64]) {
+    assert_eq!(
+      out.len(),
+      self.alpha.len(),
+      "Dirichlet::sample_into> Output length must match the parameter count."
+    );
+    loop {
+      let mut sum = 0.0;
+      for (o, a) in out.iter_mut().zip(self.alpha.iter()) {
+        *o = Gamma::new(*a, 1.0).sample(gen);
+        sum += *o;
+      }
+      if sum > 0.0 {
+        out.iter_mut().for_each(|o| *o /= sum);
+        return;
+      }
+    }
+  }
+}
@@ -0,0 +1,156 @@
+use super::*;
+
+/// Picks an index with a chance proportional to a list of weights, where the
+/// weights can be changed later.
+///
+/// * The weights are kept in a Fenwick tree (binary indexed tree), so changing
+///   one weight and sampling both take `O(log n)` time.
+/// * This is the one to use when weights change between picks, such as removing
+///   a loot entry after it drops (set its weight to 0), or decaying spawn
+///   chances over time. If the weights never change,
+///   [`WeightedAlias`](crate::WeightedAlias) samples faster.
+/// * The tree can live in an array that this owns (see
+///   [`new`](DynamicWeightedIndex::new)), or in a buffer you provide (see
+///   [`new_in`](DynamicWeightedIndex::new_in)). Neither needs allocation.
+/// * All weights start at 0. An index with a weight of 0 is never picked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DynamicWeightedIndex<B> {
+  /// `tree[i]` is the sum of the weights in `(i + 1 - lowbit(i + 1)) ..= i`.
+  tree: B,
+}
+
+impl<const N: usize> DynamicWeightedIndex<[u64; N]> {
+  /// Constructs a new `DynamicWeightedIndex`, with the tree in an array.
+  #[inline]
+  pub const fn new() -> Self {
+    Self { tree: [0; N] }
+  }
+}
+
+impl<const N: usize> Default for DynamicWeightedIndex<[u64; N]> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<'a> DynamicWeightedIndex<&'a mut [u64]> {
+  /// Constructs a new `DynamicWeightedIndex`, with the tree in the buffer
+  /// given.
+  ///
+  /// The buffer's length is the number of weights, and it's cleared to all
+  /// weights being 0.
+  #[inline]
+  pub fn new_in(buf: &'a mut [u64]) -> Self {
+    buf.iter_mut().for_each(|t| *t = 0);
+    Self { tree: buf }
+  }
+}
+
+impl<B: AsRef<[u64]> + AsMut<[u64]>> DynamicWeightedIndex<B> {
+  /// The number of weights.
+  #[inline]
+  pub fn len(&self) -> usize {
+    self.tree.as_ref().len()
+  }
+
+  /// If there are no weights.
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.tree.as_ref().is_empty()
+  }
+
+  /// The sum of all the weights.
+  #[inline]
+  pub fn total(&self) -> u64 {
+    self.prefix_sum(self.len())
+  }
+
+  /// The weight of an index.
+  ///
+  /// ## Panics
+  /// * If the index is out of bounds.
+  #[inline]
+  pub fn weight(&self, index: usize) -> u32 {
+    assert!(index < self.len(), "DynamicWeightedIndex::weight> Index out of bounds.");
+    (self.prefix_sum(index + 1) - self.prefix_sum(index)) as u32
+  }
+
+  /// Sets the weight of an index.
+  ///
+  /// ## Panics
+  /// * If the index is out of bounds.
+  #[inline]
+  pub fn set_weight(&mut self, index: usize, weight: u32) {
+    let old = self.weight(index);
+    let tree = self.tree.as_mut();
+    let mut i = index + 1;
+    while i <= tree.len() {
+      tree[i - 1] = tree[i - 1] - old as u64 + weight as u64;
+      i += i & i.wrapping_neg();
+    }
+  }
+
+  /// Sets all of the weights at once, in `O(n)` time.
+  ///
+  /// ## Panics
+  /// * If `weights` isn't the same length as this.
+  #[inline]
+  pub fn set_all(&mut self, weights: &[u32]) {
+    let tree = self.tree.as_mut();
+    assert_eq!(
+      weights.len(),
+      tree.len(),
+      "DynamicWeightedIndex::set_all> Weights must be the same length."
+    );
+    for (t, w) in tree.iter_mut().zip(weights.iter()) {
+      *t = *w as u64;
+    }
+    for i in 1..=tree.len() {
+      let parent = i + (i & i.wrapping_neg());
+      if parent <= tree.len() {
+        tree[parent - 1] += tree[i - 1];
+      }
+    }
+  }
+
+  /// Samples an index.
+  ///
+  /// ## Failure
+  /// * If all the weights are 0.
+  #[inline]
+  pub fn sample<G: Gen32 + ?Sized>(&self, gen: &mut G) -> Option<usize> {
+    let total = self.total();
+    if total == 0 {
+      return None;
+    }
+    let mut target = next_bounded_u64(gen, total);
+    // Walk down the tree, keeping `pos` as the number of weights whose sum is
+    // at most `target`.
+    let tree = self.tree.as_ref();
+    let mut pos = 0;
+    let mut step =
+      if tree.is_empty() { 0 } else { 1 << (usize::BITS - 1 - tree.len().leading_zeros()) };
+    while step > 0 {
+      let next = pos + step;
+      if next <= tree.len() && tree[next - 1] <= target {
+        pos = next;
+        target -= tree[next - 1];
+      }
+      step >>= 1;
+    }
+    Some(pos)
+  }
+
+  /// The sum of the first `count` weights.
+  #[inline]
+  fn prefix_sum(&self, count: usize) -> u64 {
+    let tree = self.tree.as_ref();
+    let mut sum = 0;
+    let mut i = count;
+    while i > 0 {
+      sum += tree[i - 1];
+      i &= i - 1;
+    }
+    sum
+  }
+}
@@ -0,0 +1,58 @@
+use super::*;
+
+/// The exponential distribution, the waiting time between events that happen
+/// at a constant average rate.
+///
+/// * `lambda` is the rate, so the mean of the output is `1.0 / lambda`.
+/// * [`sample`](Self::sample) uses a 256 layer ziggurat.
+/// * [`sample_f32`](Self::sample_f32) uses inversion with `f32` math only,
+///   which is cheaper on targets with no FPU (or only a 32-bit one). The output
+///   is limited by the 24 bits of input, so the longest possible wait is about
+///   `16.6 / lambda`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Exponential {
+  lambda: f64,
+}
+
+impl Exponential {
+  /// Constructs a new `Exponential`.
+  ///
+  /// ## Panics
+  /// * If `lambda` isn't finite, or isn't positive.
+  #[inline]
+  pub fn new(lambda: f64) -> Self {
+    Self::try_new(lambda).expect("Exponential::new> Invalid parameters.")
+  }
+
+  /// Constructs a new `Exponential`, or `None` on failure.
+  ///
+  /// ## Failure
+  /// * If `lambda` isn't finite, or isn't positive.
+  #[inline]
+  pub fn try_new(lambda: f64) -> Option<Self> {
+    if lambda.is_finite() && lambda > 0.0 {
+      Some(Self { lambda })
+    } else {
+      None
+    }
+  }
+
+  /// The rate parameter.
+  #[inline]
+  pub const fn lambda(self) -> f64 {
+    self.lambda
+  }
+
+  /// Samples a value.
+  #[inline]
+  pub fn sample<G: Gen32 + ?Sized>(self, gen: &mut G) -> f64 {
+    standard_exp(gen) / self.lambda
+  }
+
+  /// Samples a value, using only `f32` math.
+  #[inline]
+  pub fn sample_f32<G: Gen32 + ?Sized>(self, gen: &mut G) -> f32 {
+    // `1.0 - unit` is in `0.0 < x <= 1.0`, so the log is always finite.
+    -libm::logf(1.0 - gen.next_f32_unit()) / (self.lambda as f32)
+  }
+}
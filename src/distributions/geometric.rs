@@ -0,0 +1,72 @@
+use super::*;
+
+/// The geometric distribution, the number of failures before the first
+/// success, when each trial succeeds with probability `p`.
+///
+/// * [`sample`](Self::sample) uses log-inversion, so it costs one uniform value
+///   and a `ln` call no matter what `p` is.
+/// * [`sample_integer`](Self::sample_integer) runs the trials one at a time,
+///   each being a single `u32` compare. This avoids floats entirely (after
+///   construction), but takes `1 / p` steps on average, so it's only a good
+///   idea when `p` isn't small.
+/// * Outputs too large for a `u32` are clamped to `u32::MAX`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Geometric {
+  p: f64,
+  /// `ln(1 - p)`, always negative (possibly negative infinity).
+  ln_q: f64,
+  /// A `u32` is a success if it's below this, so `p * 2^32`.
+  threshold: u64,
+}
+
+impl Geometric {
+  /// Constructs a new `Geometric`.
+  ///
+  /// ## Panics
+  /// * If `p` isn't in the range `0.0 < p <= 1.0`.
+  #[inline]
+  pub fn new(p: f64) -> Self {
+    Self::try_new(p).expect("Geometric::new> Invalid parameters.")
+  }
+
+  /// Constructs a new `Geometric`, or `None` on failure.
+  ///
+  /// ## Failure
+  /// * If `p` isn't in the range `0.0 < p <= 1.0`.
+  #[inline]
+  pub fn try_new(p: f64) -> Option<Self> {
+    if p > 0.0 && p <= 1.0 {
+      let threshold = (libm::round(p * 4294967296.0) as u64).max(1);
+      Some(Self { p, ln_q: libm::log1p(-p), threshold })
+    } else {
+      None
+    }
+  }
+
+  /// The chance of success for each trial.
+  #[inline]
+  pub const fn p(self) -> f64 {
+    self.p
+  }
+
+  /// Samples a value.
+  #[inline]
+  pub fn sample<G: Gen32 + ?Sized>(self, gen: &mut G) -> u32 {
+    // `1.0 - unit` is in `0.0 < u <= 1.0`, so the log is always finite.
+    let u = 1.0 - gen.next_f64_unit();
+    libm::floor(libm::log(u) / self.ln_q) as u32
+  }
+
+  /// Samples a value by running each trial with integer math.
+  ///
+  /// The chance of success is `p` rounded to the nearest multiple of `2^-32`
+  /// (but never 0).
+  #[inline]
+  pub fn sample_integer<G: Gen32 + ?Sized>(self, gen: &mut G) -> u32 {
+    let mut failures: u32 = 0;
+    while (gen.next_u32() as u64) >= self.threshold {
+      failures = failures.saturating_add(1);
+    }
+    failures
+  }
+}
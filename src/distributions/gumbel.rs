@@ -0,0 +1,90 @@
+use super::*;
+
+/// The Gumbel distribution, which models the maximum of many samples.
+///
+/// * `mu` is the mode, and `beta` is the scale.
+/// * Sampling is by inverse CDF, taking one uniform value and two `ln` calls.
+/// * See also [`gumbel_max_pick`], which uses Gumbel noise to pick an index.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Gumbel {
+  mu: f64,
+  beta: f64,
+}
+
+impl Gumbel {
+  /// Constructs a new `Gumbel`.
+  ///
+  /// ## Panics
+  /// * If `mu` isn't finite.
+  /// * If `beta` isn't finite, or isn't positive.
+  #[inline]
+  pub fn new(mu: f64, beta: f64) -> Self {
+    Self::try_new(mu, beta).expect("Gumbel::new> Invalid parameters.")
+  }
+
+  /// Constructs a new `Gumbel`, or `None` on failure.
+  ///
+  /// ## Failure
+  /// * If `mu` isn't finite.
+  /// * If `beta` isn't finite, or isn't positive.
+  #[inline]
+  pub fn try_new(mu: f64, beta: f64) -> Option<Self> {
+    if mu.is_finite() && beta.is_finite() && beta > 0.0 {
+      Some(Self { mu, beta })
+    } else {
+      None
+    }
+  }
+
+  /// The mode.
+  #[inline]
+  pub const fn mu(self) -> f64 {
+    self.mu
+  }
+
+  /// The scale.
+  #[inline]
+  pub const fn beta(self) -> f64 {
+    self.beta
+  }
+
+  /// Samples a value.
+  #[inline]
+  pub fn sample<G: Gen32 + ?Sized>(self, gen: &mut G) -> f64 {
+    self.mu + self.beta * standard_gumbel(gen)
+  }
+}
+
+/// Samples the standard Gumbel distribution (mode 0, scale 1).
+#[inline]
+fn standard_gumbel<G: Gen32 + ?Sized>(gen: &mut G) -> f64 {
+  -libm::log(-libm::log(f64_open01(gen)))
+}
+
+/// Picks an index with probability proportional to `exp(log_weights[i])`.
+///
+/// This is the "Gumbel-max trick": add standard Gumbel noise to each log
+/// weight, and pick the biggest. The weights don't need to be normalized, and
+/// because they're never exponentiated, very large or very small values (like
+/// the logits out of a model) don't overflow or underflow.
+///
+/// * This takes one uniform value per weight.
+/// * A log weight of negative infinity is never picked.
+///
+/// ## Failure
+/// * If there are no weights, or they're all NaN or negative infinity.
+#[inline]
+pub fn gumbel_max_pick<G: Gen32 + ?Sized>(gen: &mut G, log_weights: &[f64]) -> Option<usize> {
+  let mut best: Option<(usize, f64)> = None;
+  for (i, w) in log_weights.iter().copied().enumerate() {
+    if w.is_nan() || w == f64::NEG_INFINITY {
+      continue;
+    }
+    let key = w + standard_gumbel(gen);
+    match best {
+      Some((_, best_key)) if best_key >= key => (),
+      _ => best = Some((i, key)),
+    }
+  }
+  best.map(|(i, _)| i)
+}
@@ -0,0 +1,175 @@
+use super::*;
+
+/// Up to this many (effective) draws, [`Hypergeometric`] uses inversion.
+const HYPERGEOMETRIC_INVERSION_LIMIT: u32 = 10;
+
+/// `2 * sqrt(2 / e)`
+const HRUA_D1: f64 = 1.7155277699214135;
+
+/// `3 - 2 * sqrt(3 / e)`
+const HRUA_D2: f64 = 0.8989161620588988;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum HypergeometricMethod {
+  /// Walk the PMF from 0 until it passes a uniform value.
+  Inversion { p0: f64 },
+  /// Stadlober's ratio-of-uniforms "HRUA".
+  Hrua { d6: f64, d8: f64, d10: f64, d11: f64 },
+}
+
+/// The hypergeometric distribution, the number of successes when drawing
+/// without replacement.
+///
+/// * Think of a deck of `population` cards, where `successes` of them are the
+///   cards you want. This gives how many of those you get when drawing `draws`
+///   cards, without shuffling the whole deck.
+/// * The problem is first reduced by symmetry, so only the smaller of the
+///   success and failure counts, and the smaller of the draws and non-draws,
+///   affect the cost.
+/// * When that reduced number of draws is at most 10 this uses inversion.
+///   Otherwise it uses the ratio-of-uniforms method from E. Stadlober, "The
+///   ratio of uniforms approach for generating discrete random variates"
+///   (1990), which takes a little over 2 uniform values per sample no matter
+///   how big the deck is.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hypergeometric {
+  population: u32,
+  successes: u32,
+  draws: u32,
+  /// The smaller of the successes and failures.
+  min_good_bad: u32,
+  /// The smaller of the draws and non-draws.
+  m: u32,
+  method: HypergeometricMethod,
+}
+
+impl Hypergeometric {
+  /// Constructs a new `Hypergeometric`.
+  ///
+  /// ## Panics
+  /// * If `successes` or `draws` is more than `population`.
+  #[inline]
+  pub fn new(population: u32, successes: u32, draws: u32) -> Self {
+    Self::try_new(population, successes, draws).expect("Hypergeometric::new> Invalid parameters.")
+  }
+
+  /// Constructs a new `Hypergeometric`, or `None` on failure.
+  ///
+  /// ## Failure
+  /// * If `successes` or `draws` is more than `population`.
+  #[inline]
+  pub fn try_new(population: u32, successes: u32, draws: u32) -> Option<Self> {
+    if successes > population || draws > population {
+      return None;
+    }
+    let failures = population - successes;
+    let min_good_bad = successes.min(failures);
+    let max_good_bad = successes.max(failures);
+    let m = draws.min(population - draws);
+    let (pop_f, min_f, max_f, m_f) =
+      (population as f64, min_good_bad as f64, max_good_bad as f64, m as f64);
+    // The log of the number of ways to get `z` successes, plus a constant.
+    let log_ways = |z: f64| {
+      libm::lgamma(z + 1.0)
+        + libm::lgamma(min_f - z + 1.0)
+        + libm::lgamma(m_f - z + 1.0)
+        + libm::lgamma(max_f - m_f + z + 1.0)
+    };
+    let method = if m <= HYPERGEOMETRIC_INVERSION_LIMIT {
+      // `P(0) = C(max, m) / C(pop, m)`
+      let log_p0 = libm::lgamma(max_f + 1.0) - libm::lgamma(max_f - m_f + 1.0)
+        + libm::lgamma(pop_f - m_f + 1.0)
+        - libm::lgamma(pop_f + 1.0);
+      HypergeometricMethod::Inversion { p0: libm::exp(log_p0) }
+    } else {
+      let d4 = min_f / pop_f;
+      let d5 = 1.0 - d4;
+      let d6 = m_f * d4 + 0.5;
+      let d7 = libm::sqrt((pop_f - m_f) * (draws as f64) * d4 * d5 / (pop_f - 1.0) + 0.5);
+      let d8 = HRUA_D1 * d7 + HRUA_D2;
+      let d9 = libm::floor((m_f + 1.0) * (min_f + 1.0) / (pop_f + 2.0));
+      let d10 = log_ways(d9);
+      let d11 = (m_f.min(min_f) + 1.0).min(libm::floor(d6 + 16.0 * d7));
+      HypergeometricMethod::Hrua { d6, d8, d10, d11 }
+    };
+    Some(Self { population, successes, draws, min_good_bad, m, method })
+  }
+
+  /// The total population size.
+  #[inline]
+  pub const fn population(self) -> u32 {
+    self.population
+  }
+
+  /// The number of successes in the population.
+  #[inline]
+  pub const fn successes(self) -> u32 {
+    self.successes
+  }
+
+  /// The number of draws.
+  #[inline]
+  pub const fn draws(self) -> u32 {
+    self.draws
+  }
+
+  /// Samples a value.
+  #[inline]
+  pub fn sample<G: Gen32 + ?Sized>(self, gen: &mut G) -> u32 {
+    let min_good_bad = self.min_good_bad;
+    let max_good_bad = self.population - min_good_bad;
+    let m = self.m;
+    let z = match self.method {
+      HypergeometricMethod::Inversion { p0 } => 'restart: loop {
+        let mut u = gen.next_f64_unit();
+        let mut p = p0;
+        let mut z = 0;
+        while u > p {
+          u -= p;
+          z += 1;
+          if z > m.min(min_good_bad) {
+            // Rounding error ran us off the end.
+            continue 'restart;
+          }
+          let zf = z as f64;
+          p *= (min_good_bad as f64 - zf + 1.0) * (m as f64 - zf + 1.0)
+            / (zf * (max_good_bad as f64 - m as f64 + zf));
+        }
+        break z;
+      },
+      HypergeometricMethod::Hrua { d6, d8, d10, d11 } => {
+        let (min_f, max_f, m_f) = (min_good_bad as f64, max_good_bad as f64, m as f64);
+        loop {
+          let x = f64_open01(gen);
+          let y = gen.next_f64_unit();
+          let w = d6 + d8 * (y - 0.5) / x;
+          if !(0.0..d11).contains(&w) {
+            continue;
+          }
+          let z = libm::floor(w);
+          let t = d10
+            - (libm::lgamma(z + 1.0)
+              + libm::lgamma(min_f - z + 1.0)
+              + libm::lgamma(m_f - z + 1.0)
+              + libm::lgamma(max_f - m_f + z + 1.0));
+          if x * (4.0 - x) - 3.0 <= t {
+            break z as u32;
+          }
+          if x * (x - t) >= 1.0 {
+            continue;
+          }
+          if 2.0 * libm::log(x) <= t {
+            break z as u32;
+          }
+        }
+      }
+    };
+    // Undo the symmetry reductions.
+    let z = if self.successes > self.population - self.successes { m - z } else { z };
+    if m < self.draws {
+      self.successes - z
+    } else {
+      z
+    }
+  }
+}
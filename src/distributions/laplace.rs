@@ -0,0 +1,67 @@
+use super::*;
+
+/// The Laplace (double exponential) distribution, a sharp peak at `mu` with
+/// exponential tails on both sides.
+///
+/// * `b` is the scale, the mean distance from `mu`. The variance is `2 * b^2`.
+/// * Adding Laplace noise with `b = sensitivity / epsilon` is the standard
+///   mechanism for differential privacy. Note that the generators in this crate
+///   are *not* cryptographically secure, so this alone doesn't give a real
+///   privacy guarantee.
+/// * Sampling is by inverse CDF, taking one uniform value and a `ln`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Laplace {
+  mu: f64,
+  b: f64,
+}
+
+impl Laplace {
+  /// Constructs a new `Laplace`.
+  ///
+  /// ## Panics
+  /// * If `mu` isn't finite.
+  /// * If `b` isn't finite, or isn't positive.
+  #[inline]
+  pub fn new(mu: f64, b: f64) -> Self {
+    Self::try_new(mu, b).expect("Laplace::new> Invalid parameters.")
+  }
+
+  /// Constructs a new `Laplace`, or `None` on failure.
+  ///
+  /// ## Failure
+  /// * If `mu` isn't finite.
+  /// * If `b` isn't finite, or isn't positive.
+  #[inline]
+  pub fn try_new(mu: f64, b: f64) -> Option<Self> {
+    if mu.is_finite() && b.is_finite() && b > 0.0 {
+      Some(Self { mu, b })
+    } else {
+      None
+    }
+  }
+
+  /// The location of the peak.
+  #[inline]
+  pub const fn mu(self) -> f64 {
+    self.mu
+  }
+
+  /// The scale.
+  #[inline]
+  pub const fn b(self) -> f64 {
+    self.b
+  }
+
+  /// Samples a value.
+  #[inline]
+  pub fn sample<G: Gen32 + ?Sized>(self, gen: &mut G) -> f64 {
+    // `u` is in `-0.5 < u < 0.5`, so the log is always finite.
+    let u = f64_open01(gen) - 0.5;
+    let d = self.b * libm::log(1.0 - 2.0 * libm::fabs(u));
+    if u < 0.0 {
+      self.mu + d
+    } else {
+      self.mu - d
+    }
+  }
+}
@@ -0,0 +1,54 @@
+use super::*;
+
+/// The log-normal distribution, where the log of the output is
+/// [`Normal`](crate::Normal).
+///
+/// * Good for things that can't be negative but have a long tail, like sizes
+///   and durations.
+/// * `mu` and `sigma` are the mean and standard deviation of the *log* of the
+///   output. The median of the output is `exp(mu)`.
+/// * Sampling takes one normal sample and an `exp`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LogNormal {
+  normal: Normal,
+}
+
+impl LogNormal {
+  /// Constructs a new `LogNormal`.
+  ///
+  /// ## Panics
+  /// * If `mu` isn't finite.
+  /// * If `sigma` isn't finite, or is negative.
+  #[inline]
+  pub fn new(mu: f64, sigma: f64) -> Self {
+    Self::try_new(mu, sigma).expect("LogNormal::new> Invalid parameters.")
+  }
+
+  /// Constructs a new `LogNormal`, or `None` on failure.
+  ///
+  /// ## Failure
+  /// * If `mu` isn't finite.
+  /// * If `sigma` isn't finite, or is negative.
+  #[inline]
+  pub fn try_new(mu: f64, sigma: f64) -> Option<Self> {
+    Normal::try_new(mu, sigma).map(|normal| Self { normal })
+  }
+
+  /// The mean of the log of the output.
+  #[inline]
+  pub const fn mu(self) -> f64 {
+    self.normal.mean()
+  }
+
+  /// The standard deviation of the log of the output.
+  #[inline]
+  pub const fn sigma(self) -> f64 {
+    self.normal.std_dev()
+  }
+
+  /// Samples a value.
+  #[inline]
+  pub fn sample<G: Gen32 + ?Sized>(self, gen: &mut G) -> f64 {
+    libm::exp(self.normal.sample(gen))
+  }
+}
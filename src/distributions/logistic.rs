@@ -0,0 +1,61 @@
+use super::*;
+
+/// The logistic distribution, whose CDF is the sigmoid curve.
+///
+/// * Shaped like a [`Normal`](crate::Normal) with somewhat heavier tails. The
+///   variance is `(s * PI)^2 / 3`.
+/// * Comparing a value against a logistic sample succeeds with a chance that
+///   follows a sigmoid, which makes it handy for soft random thresholds (eg: an
+///   AI that's more likely to flee the lower its health gets).
+/// * Sampling is by inverse CDF (the logit of a uniform value), taking one
+///   uniform value and a `ln`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Logistic {
+  mu: f64,
+  s: f64,
+}
+
+impl Logistic {
+  /// Constructs a new `Logistic`.
+  ///
+  /// ## Panics
+  /// * If `mu` isn't finite.
+  /// * If `s` isn't finite, or isn't positive.
+  #[inline]
+  pub fn new(mu: f64, s: f64) -> Self {
+    Self::try_new(mu, s).expect("Logistic::new> Invalid parameters.")
+  }
+
+  /// Constructs a new `Logistic`, or `None` on failure.
+  ///
+  /// ## Failure
+  /// * If `mu` isn't finite.
+  /// * If `s` isn't finite, or isn't positive.
+  #[inline]
+  pub fn try_new(mu: f64, s: f64) -> Option<Self> {
+    if mu.is_finite() && s.is_finite() && s > 0.0 {
+      Some(Self { mu, s })
+    } else {
+      None
+    }
+  }
+
+  /// The mean (and median).
+  #[inline]
+  pub const fn mu(self) -> f64 {
+    self.mu
+  }
+
+  /// The scale.
+  #[inline]
+  pub const fn s(self) -> f64 {
+    self.s
+  }
+
+  /// Samples a value.
+  #[inline]
+  pub fn sample<G: Gen32 + ?Sized>(self, gen: &mut G) -> f64 {
+    let u = f64_open01(gen);
+    self.mu + self.s * libm::log(u / (1.0 - u))
+  }
+}
@@ -0,0 +1,50 @@
+use super::*;
+
+/// Splits `n` trials between categories, writing the count for each category
+/// into `out_counts`.
+///
+/// * Each trial lands in category `i` with a chance of `probs[i]` divided by
+///   the sum of all `probs`, so the probabilities don't need to be normalized.
+/// * This uses the conditional binomial method: one [`Binomial`] sample per
+///   category, each drawing from the trials that weren't already used by the
+///   earlier categories. That's much faster than running each trial when `n` is
+///   large, and needs no allocation.
+/// * The counts always add up to exactly `n`, and a category with a probability
+///   of 0 always gets a count of 0.
+///
+/// ## Panics
+/// * If `probs` and `out_counts` aren't the same length.
+/// * If any probability isn't finite, or is negative.
+/// * If the probabilities add up to 0 (including if there are none).
+#[inline]
+pub fn multinomial<G: Gen32 + ?Sized>(gen: &mut G, n: u32, probs: &[f32], out_counts: &mut [u32]) {
+  assert_eq!(
+    probs.len(),
+    out_counts.len(),
+    "multinomial> The probabilities and counts must be the same length."
+  );
+  assert!(
+    probs.iter().all(|p| p.is_finite() && *p >= 0.0),
+    "multinomial> Probabilities must be finite and non-negative."
+  );
+  let total: f64 = probs.iter().map(|p| *p as f64).sum();
+  assert!(total > 0.0, "multinomial> Probabilities must not add up to 0.");
+  // Any trials left over at the end (from float rounding) go to the last
+  // category that can actually get them.
+  let last = probs.iter().rposition(|p| *p > 0.0).unwrap();
+  let mut remaining_n = n;
+  let mut remaining_p = total;
+  for (i, (p, out)) in probs.iter().zip(out_counts.iter_mut()).enumerate() {
+    let p = *p as f64;
+    *out = if i == last {
+      remaining_n
+    } else if i > last || p == 0.0 || remaining_n == 0 {
+      0
+    } else {
+      let k = Binomial::new(remaining_n, (p / remaining_p).min(1.0)).sample(gen);
+      remaining_n -= k;
+      remaining_p -= p;
+      k
+    };
+  }
+}
@@ -0,0 +1,100 @@
+use super::*;
+
+/// Up to this `r`, a whole-number `r` is sampled by adding up geometric values.
+const NEGATIVE_BINOMIAL_SUM_LIMIT: u32 = 16;
+
+/// The negative binomial distribution, the number of failures before the
+/// `r`th success, when each trial succeeds with probability `p`.
+///
+/// * Compared to a [`Poisson`](crate::Poisson) with the same mean, this has a
+///   bigger variance, so it's good for "clumpy" counts like clustered spawns.
+/// * `r` doesn't need to be a whole number.
+/// * When `r` is a whole number up to 16, [`sample`](Self::sample) adds up `r`
+///   [`Geometric`](crate::Geometric) values. Otherwise it uses a Gamma-Poisson
+///   mixture: a [`Gamma`](crate::Gamma) value is used as the mean of a Poisson
+///   sample.
+/// * [`sample_integer`](Self::sample_integer) adds up geometric values that use
+///   integer math only.
+/// * Outputs too large for a `u32` are clamped to `u32::MAX`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NegativeBinomial {
+  r: f64,
+  p: f64,
+  /// `r` as a `u32`, if it's a whole number that fits.
+  r_whole: Option<u32>,
+  geometric: Geometric,
+  /// The mixing distribution, `None` if `p` is 1.
+  gamma: Option<Gamma>,
+}
+
+impl NegativeBinomial {
+  /// Constructs a new `NegativeBinomial`.
+  ///
+  /// ## Panics
+  /// * If `r` isn't finite, or isn't positive.
+  /// * If `p` isn't in the range `0.0 < p <= 1.0`.
+  #[inline]
+  pub fn new(r: f64, p: f64) -> Self {
+    Self::try_new(r, p).expect("NegativeBinomial::new> Invalid parameters.")
+  }
+
+  /// Constructs a new `NegativeBinomial`, or `None` on failure.
+  ///
+  /// ## Failure
+  /// * If `r` isn't finite, or isn't positive.
+  /// * If `p` isn't in the range `0.0 < p <= 1.0`.
+  #[inline]
+  pub fn try_new(r: f64, p: f64) -> Option<Self> {
+    if !(r.is_finite() && r > 0.0) {
+      return None;
+    }
+    let geometric = Geometric::try_new(p)?;
+    let gamma = if p < 1.0 { Some(Gamma::try_new(r, (1.0 - p) / p)?) } else { None };
+    let r_whole = if libm::floor(r) == r && r <= u32::MAX as f64 { Some(r as u32) } else { None };
+    Some(Self { r, p, r_whole, geometric, gamma })
+  }
+
+  /// The number of successes to wait for.
+  #[inline]
+  pub const fn r(self) -> f64 {
+    self.r
+  }
+
+  /// The chance of success for each trial.
+  #[inline]
+  pub const fn p(self) -> f64 {
+    self.p
+  }
+
+  /// Samples a value.
+  #[inline]
+  pub fn sample<G: Gen32 + ?Sized>(self, gen: &mut G) -> u32 {
+    let gamma = match self.gamma {
+      Some(gamma) => gamma,
+      None => return 0,
+    };
+    match self.r_whole {
+      Some(r) if r <= NEGATIVE_BINOMIAL_SUM_LIMIT => {
+        (0..r).fold(0_u32, |acc, _| acc.saturating_add(self.geometric.sample(gen)))
+      }
+      _ => match Poisson::try_new(gamma.sample(gen)) {
+        Some(poisson) => poisson.sample(gen),
+        // The gamma value can round down to exactly 0.
+        None => 0,
+      },
+    }
+  }
+
+  /// Samples a value by adding up [`Geometric::sample_integer`] values.
+  ///
+  /// This takes about `r / p` steps, so it's only a good idea when `r` is small
+  /// and `p` isn't small.
+  ///
+  /// ## Panics
+  /// * If `r` isn't a whole number (that fits in a `u32`).
+  #[inline]
+  pub fn sample_integer<G: Gen32 + ?Sized>(self, gen: &mut G) -> u32 {
+    let r = self.r_whole.expect("NegativeBinomial::sample_integer> `r` must be a whole number.");
+    (0..r).fold(0_u32, |acc, _| acc.saturating_add(self.geometric.sample_integer(gen)))
+  }
+}
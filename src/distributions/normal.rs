@@ -0,0 +1,65 @@
+use super::*;
+
+/// The normal (Gaussian) distribution.
+///
+/// * Sampled with a 256 layer ziggurat, so most samples cost one `u64` from the
+///   generator, a table lookup, a multiply, and a compare.
+/// * A standard deviation of 0 is allowed, and always gives the mean.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Normal {
+  mean: f64,
+  std_dev: f64,
+}
+
+impl Normal {
+  /// The standard normal distribution, with mean 0 and standard deviation 1.
+  pub const STANDARD: Self = Self { mean: 0.0, std_dev: 1.0 };
+
+  /// Constructs a new `Normal`.
+  ///
+  /// ## Panics
+  /// * If the mean isn't finite.
+  /// * If the standard deviation isn't finite, or is negative.
+  #[inline]
+  pub fn new(mean: f64, std_dev: f64) -> Self {
+    Self::try_new(mean, std_dev).expect("Normal::new> Invalid parameters.")
+  }
+
+  /// Constructs a new `Normal`, or `None` on failure.
+  ///
+  /// ## Failure
+  /// * If the mean isn't finite.
+  /// * If the standard deviation isn't finite, or is negative.
+  #[inline]
+  pub fn try_new(mean: f64, std_dev: f64) -> Option<Self> {
+    if mean.is_finite() && std_dev.is_finite() && std_dev >= 0.0 {
+      Some(Self { mean, std_dev })
+    } else {
+      None
+    }
+  }
+
+  /// The mean.
+  #[inline]
+  pub const fn mean(self) -> f64 {
+    self.mean
+  }
+
+  /// The standard deviation.
+  #[inline]
+  pub const fn std_dev(self) -> f64 {
+    self.std_dev
+  }
+
+  /// Samples a value.
+  #[inline]
+  pub fn sample<G: Gen32 + ?Sized>(self, gen: &mut G) -> f64 {
+    self.mean + self.std_dev * standard_normal(gen)
+  }
+}
+
+impl Default for Normal {
+  fn default() -> Self {
+    Self::STANDARD
+  }
+}
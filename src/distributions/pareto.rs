@@ -0,0 +1,62 @@
+use super::*;
+
+/// The Pareto distribution, a power law over `scale ..`.
+///
+/// * Most values are close to `scale`, but there's a long tail of much larger
+///   values. A smaller `shape` gives a heavier tail.
+/// * The mean is `shape * scale / (shape - 1)` for a shape over 1, and infinite
+///   otherwise.
+/// * Sampling is by inverse CDF, `scale * u^(-1 / shape)` with `u` from `0.0 <
+///   u <= 1.0`. This means the output is in the half-open range `scale ..`: the
+///   lower end is included (from `u = 1`), and the output is always finite, but
+///   the largest possible output depends on the smallest non-zero `u`, which is
+///   `2^-53`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pareto {
+  scale: f64,
+  shape: f64,
+  inv_neg_shape: f64,
+}
+
+impl Pareto {
+  /// Constructs a new `Pareto`.
+  ///
+  /// ## Panics
+  /// * If either parameter isn't finite, or isn't positive.
+  #[inline]
+  pub fn new(scale: f64, shape: f64) -> Self {
+    Self::try_new(scale, shape).expect("Pareto::new> Invalid parameters.")
+  }
+
+  /// Constructs a new `Pareto`, or `None` on failure.
+  ///
+  /// ## Failure
+  /// * If either parameter isn't finite, or isn't positive.
+  #[inline]
+  pub fn try_new(scale: f64, shape: f64) -> Option<Self> {
+    if scale.is_finite() && scale > 0.0 && shape.is_finite() && shape > 0.0 {
+      Some(Self { scale, shape, inv_neg_shape: -1.0 / shape })
+    } else {
+      None
+    }
+  }
+
+  /// The scale, which is also the smallest possible output.
+  #[inline]
+  pub const fn scale(self) -> f64 {
+    self.scale
+  }
+
+  /// The shape (also called the tail index).
+  #[inline]
+  pub const fn shape(self) -> f64 {
+    self.shape
+  }
+
+  /// Samples a value.
+  #[inline]
+  pub fn sample<G: Gen32 + ?Sized>(self, gen: &mut G) -> f64 {
+    let u = 1.0 - gen.next_f64_unit();
+    self.scale * libm::pow(u, self.inv_neg_shape)
+  }
+}
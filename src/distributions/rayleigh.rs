@@ -0,0 +1,49 @@
+use super::*;
+
+/// The Rayleigh distribution, the length of a 2D vector whose components are
+/// independent normal values with standard deviation `sigma`.
+///
+/// * Good for magnitudes of 2D noise, like wind speed or the speed of a
+///   particle shot in a random direction.
+/// * The mean is `sigma * sqrt(PI / 2)`.
+/// * Sampling takes one exponential value and a `sqrt`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rayleigh {
+  sigma: f64,
+}
+
+impl Rayleigh {
+  /// Constructs a new `Rayleigh`.
+  ///
+  /// ## Panics
+  /// * If `sigma` isn't finite, or isn't positive.
+  #[inline]
+  pub fn new(sigma: f64) -> Self {
+    Self::try_new(sigma).expect("Rayleigh::new> Invalid parameters.")
+  }
+
+  /// Constructs a new `Rayleigh`, or `None` on failure.
+  ///
+  /// ## Failure
+  /// * If `sigma` isn't finite, or isn't positive.
+  #[inline]
+  pub fn try_new(sigma: f64) -> Option<Self> {
+    if sigma.is_finite() && sigma > 0.0 {
+      Some(Self { sigma })
+    } else {
+      None
+    }
+  }
+
+  /// The scale.
+  #[inline]
+  pub const fn sigma(self) -> f64 {
+    self.sigma
+  }
+
+  /// Samples a value.
+  #[inline]
+  pub fn sample<G: Gen32 + ?Sized>(self, gen: &mut G) -> f64 {
+    self.sigma * libm::sqrt(2.0 * standard_exp(gen))
+  }
+}
@@ -0,0 +1,52 @@
+use super::*;
+
+/// Student's t distribution, with `dof` degrees of freedom.
+///
+/// * This is a bell shape like the standard [`Normal`](crate::Normal), but with
+///   heavier tails. Fewer degrees of freedom give heavier tails, and as the
+///   degrees of freedom grow it approaches the standard normal.
+/// * Sampling takes one normal value and one chi-squared value (which is a
+///   [`Gamma`](crate::Gamma) value), as `z / sqrt(chi_sq / dof)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StudentT {
+  dof: f64,
+  /// The chi-squared distribution with `dof` degrees of freedom.
+  chi_sq: Gamma,
+}
+
+impl StudentT {
+  /// Constructs a new `StudentT`.
+  ///
+  /// ## Panics
+  /// * If `dof` isn't finite, or isn't positive.
+  #[inline]
+  pub fn new(dof: f64) -> Self {
+    Self::try_new(dof).expect("StudentT::new> Invalid parameters.")
+  }
+
+  /// Constructs a new `StudentT`, or `None` on failure.
+  ///
+  /// ## Failure
+  /// * If `dof` isn't finite, or isn't positive.
+  #[inline]
+  pub fn try_new(dof: f64) -> Option<Self> {
+    if dof.is_finite() && dof > 0.0 {
+      Gamma::try_new(dof / 2.0, 2.0).map(|chi_sq| Self { dof, chi_sq })
+    } else {
+      None
+    }
+  }
+
+  /// The degrees of freedom.
+  #[inline]
+  pub const fn dof(self) -> f64 {
+    self.dof
+  }
+
+  /// Samples a value.
+  #[inline]
+  pub fn sample<G: Gen32 + ?Sized>(self, gen: &mut G) -> f64 {
+    let z = standard_normal(gen);
+    z * libm::sqrt(self.dof / self.chi_sq.sample(gen))
+  }
+}
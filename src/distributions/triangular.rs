@@ -0,0 +1,189 @@
+use super::*;
+
+/// The triangular distribution, with a pdf that rises in a straight line from
+/// `min` up to `mode`, then falls in a straight line down to `max`.
+///
+/// * This is a cheap way to get "mostly middle values, with rare extremes"
+///   without needing any parameters beyond the range and the peak.
+/// * Sampling is by inversion, taking one uniform value and one square root.
+/// * [`sample_f32`](Self::sample_f32) does the same using only `f32` math.
+/// * See [`TriangularI32`] for a version that uses only integer math.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Triangular {
+  min: f64,
+  mode: f64,
+  max: f64,
+  /// The CDF at the mode, `(mode - min) / (max - min)`.
+  mode_cdf: f64,
+}
+
+impl Triangular {
+  /// Constructs a new `Triangular`.
+  ///
+  /// ## Panics
+  /// * If any value isn't finite.
+  /// * If the values aren't in the order `min <= mode <= max`.
+  #[inline]
+  pub fn new(min: f64, mode: f64, max: f64) -> Self {
+    Self::try_new(min, mode, max).expect("Triangular::new> Invalid parameters.")
+  }
+
+  /// Constructs a new `Triangular`, or `None` on failure.
+  ///
+  /// ## Failure
+  /// * If any value isn't finite.
+  /// * If the values aren't in the order `min <= mode <= max`.
+  #[inline]
+  pub fn try_new(min: f64, mode: f64, max: f64) -> Option<Self> {
+    if !(min.is_finite() && mode.is_finite() && max.is_finite() && min <= mode && mode <= max) {
+      return None;
+    }
+    let mode_cdf = if max > min { (mode - min) / (max - min) } else { 0.5 };
+    Some(Self { min, mode, max, mode_cdf })
+  }
+
+  /// The lowest possible value.
+  #[inline]
+  pub const fn min(self) -> f64 {
+    self.min
+  }
+
+  /// The most likely value.
+  #[inline]
+  pub const fn mode(self) -> f64 {
+    self.mode
+  }
+
+  /// The highest possible value.
+  #[inline]
+  pub const fn max(self) -> f64 {
+    self.max
+  }
+
+  /// Samples a value in `min ..= max`.
+  #[inline]
+  pub fn sample<G: Gen32 + ?Sized>(self, gen: &mut G) -> f64 {
+    let u = gen.next_f64_unit();
+    let range = self.max - self.min;
+    if u < self.mode_cdf {
+      self.min + libm::sqrt(u * range * (self.mode - self.min))
+    } else {
+      self.max - libm::sqrt((1.0 - u) * range * (self.max - self.mode))
+    }
+  }
+
+  /// Samples a value in `min ..= max`, using only `f32` math.
+  #[inline]
+  pub fn sample_f32<G: Gen32 + ?Sized>(self, gen: &mut G) -> f32 {
+    let (min, mode, max) = (self.min as f32, self.mode as f32, self.max as f32);
+    let u = gen.next_f32_unit();
+    let range = max - min;
+    if u < self.mode_cdf as f32 {
+      min + libm::sqrtf(u * range * (mode - min))
+    } else {
+      max - libm::sqrtf((1.0 - u) * range * (max - mode))
+    }
+  }
+}
+
+/// The triangular distribution, using only integer math.
+///
+/// * This works like [`Triangular`], but over `i32` values, so it's usable on
+///   targets with no FPU. Fixed-point values work too, just pass them in and
+///   interpret the output with the same scale.
+/// * Outputs are in `min ..= max`, rounded down from the exact inverse CDF.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TriangularI32 {
+  min: i32,
+  mode: i32,
+  max: i32,
+  /// The CDF at the mode, as a fraction of `2^32`.
+  mode_cdf: u64,
+}
+
+impl TriangularI32 {
+  /// Constructs a new `TriangularI32`.
+  ///
+  /// ## Panics
+  /// * If the values aren't in the order `min <= mode <= max`.
+  #[inline]
+  pub const fn new(min: i32, mode: i32, max: i32) -> Self {
+    match Self::try_new(min, mode, max) {
+      Some(t) => t,
+      None => panic!("TriangularI32::new> Invalid parameters."),
+    }
+  }
+
+  /// Constructs a new `TriangularI32`, or `None` on failure.
+  ///
+  /// ## Failure
+  /// * If the values aren't in the order `min <= mode <= max`.
+  #[inline]
+  pub const fn try_new(min: i32, mode: i32, max: i32) -> Option<Self> {
+    if !(min <= mode && mode <= max) {
+      return None;
+    }
+    let range = max.wrapping_sub(min) as u32 as u64;
+    let left = mode.wrapping_sub(min) as u32 as u64;
+    let mode_cdf = match (left << 32).checked_div(range) {
+      Some(mode_cdf) => mode_cdf,
+      None => 1 << 31,
+    };
+    Some(Self { min, mode, max, mode_cdf })
+  }
+
+  /// The lowest possible value.
+  #[inline]
+  pub const fn min(self) -> i32 {
+    self.min
+  }
+
+  /// The most likely value.
+  #[inline]
+  pub const fn mode(self) -> i32 {
+    self.mode
+  }
+
+  /// The highest possible value.
+  #[inline]
+  pub const fn max(self) -> i32 {
+    self.max
+  }
+
+  /// Samples a value in `min ..= max`.
+  #[inline]
+  pub fn sample<G: Gen32 + ?Sized>(self, gen: &mut G) -> i32 {
+    let u = gen.next_u32() as u64;
+    let range = self.max.wrapping_sub(self.min) as u32 as u128;
+    if u < self.mode_cdf {
+      let left = self.mode.wrapping_sub(self.min) as u32 as u128;
+      let offset = isqrt_u64(((u as u128 * range * left) >> 32) as u64);
+      self.min.wrapping_add(offset as i32)
+    } else {
+      let right = self.max.wrapping_sub(self.mode) as u32 as u128;
+      let v = (1_u128 << 32) - u as u128;
+      let offset = isqrt_u64(((v * range * right) >> 32) as u64);
+      self.max.wrapping_sub(offset as i32)
+    }
+  }
+}
+
+/// The integer square root of a `u64`, rounded down.
+const fn isqrt_u64(n: u64) -> u64 {
+  let mut rem = n;
+  let mut root = 0_u64;
+  let mut bit = 1_u64 << 62;
+  while bit > n {
+    bit >>= 2;
+  }
+  while bit != 0 {
+    if rem >= root + bit {
+      rem -= root + bit;
+      root = (root >> 1) + bit;
+    } else {
+      root >>= 1;
+    }
+    bit >>= 2;
+  }
+  root
+}
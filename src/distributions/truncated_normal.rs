@@ -0,0 +1,145 @@
+use super::*;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TruncatedNormalMethod {
+  /// Sample the full normal and reject anything outside the range.
+  Normal,
+  /// Sample uniformly in the range, and accept with the pdf ratio.
+  Uniform,
+  /// Sample a shifted exponential past `a` (in the upper tail).
+  Exponential { alpha: f64 },
+}
+
+/// The normal distribution, limited to the range `lo ..= hi`.
+///
+/// * The output has the shape of the [`Normal`](crate::Normal) with the given
+///   `mean` and `std_dev`, with the parts outside the range cut off. Clamping
+///   normal samples instead would pile up values at the edges, and simply
+///   resampling until you get a value in range can be incredibly slow when the
+///   range is far out in a tail.
+/// * Sampling uses the methods from C. P. Robert, "Simulation of truncated
+///   normal variables" (1995), picking between normal rejection, uniform
+///   rejection, and exponential rejection based on the range. The expected
+///   number of tries per sample is always small, no matter where the range is.
+/// * Either end of the range can be infinite.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TruncatedNormal {
+  mean: f64,
+  std_dev: f64,
+  lo: f64,
+  hi: f64,
+  /// The range to sample from a standard normal, after standardizing (and
+  /// possibly flipping, see `flipped`).
+  a: f64,
+  b: f64,
+  /// If the range was negated, to put it in the upper tail.
+  flipped: bool,
+  method: TruncatedNormalMethod,
+}
+
+impl TruncatedNormal {
+  /// Constructs a new `TruncatedNormal`.
+  ///
+  /// ## Panics
+  /// * If the mean isn't finite.
+  /// * If the standard deviation isn't finite, or isn't positive.
+  /// * If `lo` isn't less than `hi`.
+  #[inline]
+  pub fn new(mean: f64, std_dev: f64, lo: f64, hi: f64) -> Self {
+    Self::try_new(mean, std_dev, lo, hi).expect("TruncatedNormal::new> Invalid parameters.")
+  }
+
+  /// Constructs a new `TruncatedNormal`, or `None` on failure.
+  ///
+  /// ## Failure
+  /// * If the mean isn't finite.
+  /// * If the standard deviation isn't finite, or isn't positive.
+  /// * If `lo` isn't less than `hi`.
+  #[inline]
+  pub fn try_new(mean: f64, std_dev: f64, lo: f64, hi: f64) -> Option<Self> {
+    if !(mean.is_finite() && std_dev.is_finite() && std_dev > 0.0 && lo < hi) {
+      return None;
+    }
+    let a = (lo - mean) / std_dev;
+    let b = (hi - mean) / std_dev;
+    let (a, b, flipped) = if b <= 0.0 { (-b, -a, true) } else { (a, b, false) };
+    let method = if a < 0.0 {
+      // The range includes the mean.
+      if b - a >= libm::sqrt(2.0 * core::f64::consts::PI) {
+        TruncatedNormalMethod::Normal
+      } else {
+        TruncatedNormalMethod::Uniform
+      }
+    } else {
+      // The range is entirely in the upper tail.
+      let alpha = (a + libm::sqrt(a * a + 4.0)) / 2.0;
+      let uniform_limit = a
+        + 2.0 / (a + libm::sqrt(a * a + 4.0))
+          * libm::exp((a * a - a * libm::sqrt(a * a + 4.0)) / 4.0 + 0.5);
+      if b <= uniform_limit {
+        TruncatedNormalMethod::Uniform
+      } else {
+        TruncatedNormalMethod::Exponential { alpha }
+      }
+    };
+    Some(Self { mean, std_dev, lo, hi, a, b, flipped, method })
+  }
+
+  /// The mean of the untruncated normal.
+  #[inline]
+  pub const fn mean(self) -> f64 {
+    self.mean
+  }
+
+  /// The standard deviation of the untruncated normal.
+  #[inline]
+  pub const fn std_dev(self) -> f64 {
+    self.std_dev
+  }
+
+  /// The lower end of the range.
+  #[inline]
+  pub const fn lo(self) -> f64 {
+    self.lo
+  }
+
+  /// The upper end of the range.
+  #[inline]
+  pub const fn hi(self) -> f64 {
+    self.hi
+  }
+
+  /// Samples a value in `lo ..= hi`.
+  #[inline]
+  pub fn sample<G: Gen32 + ?Sized>(self, gen: &mut G) -> f64 {
+    let (a, b) = (self.a, self.b);
+    let z = match self.method {
+      TruncatedNormalMethod::Normal => loop {
+        let z = standard_normal(gen);
+        if a <= z && z <= b {
+          break z;
+        }
+      },
+      TruncatedNormalMethod::Uniform => loop {
+        let z = a + (b - a) * gen.next_f64_unit();
+        // The pdf ratio against the peak of the pdf within the range.
+        let log_rho = if a > 0.0 { (a * a - z * z) / 2.0 } else { -z * z / 2.0 };
+        if libm::log(f64_open01(gen)) <= log_rho {
+          break z;
+        }
+      },
+      TruncatedNormalMethod::Exponential { alpha } => loop {
+        let z = a + standard_exp(gen) / alpha;
+        if z > b {
+          continue;
+        }
+        let d = z - alpha;
+        if libm::log(f64_open01(gen)) <= -d * d / 2.0 {
+          break z;
+        }
+      },
+    };
+    let z = if self.flipped { -z } else { z };
+    (self.mean + self.std_dev * z).clamp(self.lo, self.hi)
+  }
+}
@@ -0,0 +1,88 @@
+use super::*;
+
+use core::f64::consts::PI;
+
+/// Below this `kappa` the [`VonMises`] distribution is treated as uniform.
+const VON_MISES_UNIFORM_KAPPA: f64 = 1e-6;
+
+/// The von Mises distribution, a "normal distribution on a circle", for
+/// random angles (in radians) clustered around a direction.
+///
+/// * `mu` is the center direction. Outputs are in `mu - PI ..= mu + PI`, they
+///   aren't wrapped into any particular range.
+/// * `kappa` is the concentration. At 0 every angle is equally likely, and as
+///   it grows the angles bunch up around `mu`, like a normal distribution with
+///   a variance of `1 / kappa`.
+/// * Sampling uses the rejection method from D. J. Best and N. I. Fisher,
+///   "Efficient simulation of the von Mises distribution" (1979), which takes 3
+///   uniform values per try and accepts at least 65% of tries.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VonMises {
+  mu: f64,
+  kappa: f64,
+  /// `r` from the paper.
+  r: f64,
+}
+
+impl VonMises {
+  /// Constructs a new `VonMises`.
+  ///
+  /// ## Panics
+  /// * If `mu` isn't finite.
+  /// * If `kappa` isn't finite, or is negative.
+  #[inline]
+  pub fn new(mu: f64, kappa: f64) -> Self {
+    Self::try_new(mu, kappa).expect("VonMises::new> Invalid parameters.")
+  }
+
+  /// Constructs a new `VonMises`, or `None` on failure.
+  ///
+  /// ## Failure
+  /// * If `mu` isn't finite.
+  /// * If `kappa` isn't finite, or is negative.
+  #[inline]
+  pub fn try_new(mu: f64, kappa: f64) -> Option<Self> {
+    if !(mu.is_finite() && kappa.is_finite() && kappa >= 0.0) {
+      return None;
+    }
+    let r = if kappa < VON_MISES_UNIFORM_KAPPA {
+      0.0
+    } else {
+      let tau = 1.0 + libm::sqrt(1.0 + 4.0 * kappa * kappa);
+      let rho = (tau - libm::sqrt(2.0 * tau)) / (2.0 * kappa);
+      (1.0 + rho * rho) / (2.0 * rho)
+    };
+    Some(Self { mu, kappa, r })
+  }
+
+  /// The center direction.
+  #[inline]
+  pub const fn mu(self) -> f64 {
+    self.mu
+  }
+
+  /// The concentration.
+  #[inline]
+  pub const fn kappa(self) -> f64 {
+    self.kappa
+  }
+
+  /// Samples an angle.
+  #[inline]
+  pub fn sample<G: Gen32 + ?Sized>(self, gen: &mut G) -> f64 {
+    if self.kappa < VON_MISES_UNIFORM_KAPPA {
+      return self.mu + PI * (2.0 * gen.next_f64_unit() - 1.0);
+    }
+    loop {
+      let u1 = gen.next_f64_unit();
+      let u2 = f64_open01(gen);
+      let z = libm::cos(PI * u1);
+      let f = (1.0 + self.r * z) / (self.r + z);
+      let c = self.kappa * (self.r - f);
+      if c * (2.0 - c) - u2 > 0.0 || libm::log(c / u2) + 1.0 - c >= 0.0 {
+        let theta = libm::acos(f.clamp(-1.0, 1.0));
+        return if gen.next_bool() { self.mu + theta } else { self.mu - theta };
+      }
+    }
+  }
+}
@@ -0,0 +1,59 @@
+use super::*;
+
+/// The Weibull distribution, often used for the time until a part fails.
+///
+/// * A shape below 1 means failures get less likely over time (early defects),
+///   a shape of 1 is the [`Exponential`](crate::Exponential) distribution, and
+///   a shape above 1 means failures get more likely over time (wear).
+/// * `scale` is the time by which about 63% of parts have failed.
+/// * Sampling is by inverse CDF, taking one uniform value, a `ln`, and a `pow`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Weibull {
+  scale: f64,
+  shape: f64,
+  inv_shape: f64,
+}
+
+impl Weibull {
+  /// Constructs a new `Weibull`.
+  ///
+  /// ## Panics
+  /// * If either parameter isn't finite, or isn't positive.
+  #[inline]
+  pub fn new(scale: f64, shape: f64) -> Self {
+    Self::try_new(scale, shape).expect("Weibull::new> Invalid parameters.")
+  }
+
+  /// Constructs a new `Weibull`, or `None` on failure.
+  ///
+  /// ## Failure
+  /// * If either parameter isn't finite, or isn't positive.
+  #[inline]
+  pub fn try_new(scale: f64, shape: f64) -> Option<Self> {
+    if scale.is_finite() && scale > 0.0 && shape.is_finite() && shape > 0.0 {
+      Some(Self { scale, shape, inv_shape: 1.0 / shape })
+    } else {
+      None
+    }
+  }
+
+  /// The scale.
+  #[inline]
+  pub const fn scale(self) -> f64 {
+    self.scale
+  }
+
+  /// The shape.
+  #[inline]
+  pub const fn shape(self) -> f64 {
+    self.shape
+  }
+
+  /// Samples a value.
+  #[inline]
+  pub fn sample<G: Gen32 + ?Sized>(self, gen: &mut G) -> f64 {
+    // `1.0 - unit` is in `0.0 < u <= 1.0`, so the log is always finite.
+    let u = 1.0 - gen.next_f64_unit();
+    self.scale * libm::pow(-libm::log(u), self.inv_shape)
+  }
+}
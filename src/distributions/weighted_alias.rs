@@ -0,0 +1,186 @@
+use super::*;
+
+/// The alias table equivalent of probability 1, `2^32`.
+const ALIAS_ONE: u64 = 1 << 32;
+
+/// Marks the end of a list while building an alias table.
+const ALIAS_NONE: u32 = u32::MAX;
+
+/// One column of a [`WeightedAlias`] table.
+///
+/// You only need to name this type to make a buffer for
+/// [`WeightedAlias::new_in`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct AliasEntry {
+  /// A `u32` coin below this keeps the column, otherwise the alias is used.
+  ///
+  /// While building the table, this is the column's remaining weight.
+  threshold: u64,
+  /// The other index this column can give.
+  ///
+  /// While building the table, this is the next index in a work list.
+  alias: u32,
+}
+
+/// Picks an index with a chance proportional to a list of weights, in `O(1)`
+/// time per sample.
+///
+/// * This is Walker's alias method, built with Vose's `O(n)` algorithm. Each
+///   sample picks a column uniformly, then flips a biased coin to pick between
+///   the column's own index and its alias. That's a `next_bounded` and a
+///   `next_u32`, no matter how many weights there are.
+/// * Weights can be any type that converts to `f64`, such as `u32` or `f32`.
+/// * The table can live in an array that this owns (see
+///   [`new`](WeightedAlias::new)), or in a buffer of [`AliasEntry`] values that
+///   you provide (see [`new_in`](WeightedAlias::new_in)). Neither needs
+///   allocation, which makes this a good fit for hot loot or spawn tables on
+///   small targets.
+/// * Probabilities are rounded to multiples of `2^-32` (per column). An index
+///   with a weight of 0 is never picked.
+/// * See [`Categorical`](crate::Categorical) for a smaller table, with `O(log
+///   n)` sampling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WeightedAlias<B> {
+  table: B,
+}
+
+impl<const N: usize> WeightedAlias<[AliasEntry; N]> {
+  /// Constructs a new `WeightedAlias`, with the table in an array.
+  ///
+  /// ## Panics
+  /// * See [`try_new`](Self::try_new).
+  #[inline]
+  pub fn new<W: Copy + Into<f64>>(weights: &[W; N]) -> Self {
+    Self::try_new(weights).expect("WeightedAlias::new> Invalid weights.")
+  }
+
+  /// Constructs a new `WeightedAlias` with the table in an array, or `None` on
+  /// failure.
+  ///
+  /// ## Failure
+  /// * If any weight isn't finite, or is negative.
+  /// * If the weights add up to 0 (including if there are none).
+  /// * If there are `u32::MAX` or more weights.
+  #[inline]
+  pub fn try_new<W: Copy + Into<f64>>(weights: &[W; N]) -> Option<Self> {
+    let mut table = [AliasEntry::default(); N];
+    if build_alias_table(weights, &mut table) {
+      Some(Self { table })
+    } else {
+      None
+    }
+  }
+}
+
+impl<'a> WeightedAlias<&'a mut [AliasEntry]> {
+  /// Constructs a new `WeightedAlias`, with the table in the buffer given.
+  ///
+  /// ## Panics
+  /// * See [`try_new_in`](Self::try_new_in).
+  #[inline]
+  pub fn new_in<W: Copy + Into<f64>>(weights: &[W], buf: &'a mut [AliasEntry]) -> Self {
+    Self::try_new_in(weights, buf).expect("WeightedAlias::new_in> Invalid weights.")
+  }
+
+  /// Constructs a new `WeightedAlias` with the table in the buffer given, or
+  /// `None` on failure.
+  ///
+  /// ## Failure
+  /// * If the buffer isn't the same length as `weights`.
+  /// * If any weight isn't finite, or is negative.
+  /// * If the weights add up to 0 (including if there are none).
+  /// * If there are `u32::MAX` or more weights.
+  #[inline]
+  pub fn try_new_in<W: Copy + Into<f64>>(weights: &[W], buf: &'a mut [AliasEntry]) -> Option<Self> {
+    if weights.len() == buf.len() && build_alias_table(weights, buf) {
+      Some(Self { table: buf })
+    } else {
+      None
+    }
+  }
+}
+
+impl<B: AsRef<[AliasEntry]>> WeightedAlias<B> {
+  /// The number of weights.
+  #[inline]
+  pub fn len(&self) -> usize {
+    self.table.as_ref().len()
+  }
+
+  /// If there are no weights (which is never the case).
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.table.as_ref().is_empty()
+  }
+
+  /// Samples an index.
+  #[inline]
+  pub fn sample<G: Gen32 + ?Sized>(&self, gen: &mut G) -> usize {
+    let table = self.table.as_ref();
+    let column = gen.next_bounded(table.len() as u32) as usize;
+    let entry = table[column];
+    if (gen.next_u32() as u64) < entry.threshold {
+      column
+    } else {
+      entry.alias as usize
+    }
+  }
+}
+
+/// Builds an alias table for `weights` into `table`, which must be the same
+/// length, or gives `false` if the weights are invalid.
+fn build_alias_table<W: Copy + Into<f64>>(weights: &[W], table: &mut [AliasEntry]) -> bool {
+  let n = weights.len();
+  if n == 0 || n >= ALIAS_NONE as usize {
+    return false;
+  }
+  if !weights.iter().all(|w| {
+    let w: f64 = (*w).into();
+    w.is_finite() && w >= 0.0
+  }) {
+    return false;
+  }
+  let total: f64 = weights.iter().map(|w| (*w).into()).sum();
+  if total <= 0.0 {
+    return false;
+  }
+  // Scale the weights so that the average is `ALIAS_ONE`, and sort them into a
+  // "small" and a "large" list. The lists are linked through `alias`.
+  let scale = (n as f64) * (ALIAS_ONE as f64) / total;
+  let mut small = ALIAS_NONE;
+  let mut large = ALIAS_NONE;
+  for (i, (w, entry)) in weights.iter().zip(table.iter_mut()).enumerate() {
+    let w: f64 = (*w).into();
+    entry.threshold = libm::round(w * scale) as u64;
+    if entry.threshold < ALIAS_ONE {
+      entry.alias = small;
+      small = i as u32;
+    } else {
+      entry.alias = large;
+      large = i as u32;
+    }
+  }
+  // Fill up each small column from a large one.
+  while small != ALIAS_NONE && large != ALIAS_NONE {
+    let s = small as usize;
+    small = table[s].alias;
+    table[s].alias = large;
+    let l = large as usize;
+    let given = ALIAS_ONE - table[s].threshold;
+    table[l].threshold = table[l].threshold.saturating_sub(given);
+    if table[l].threshold < ALIAS_ONE {
+      large = table[l].alias;
+      table[l].alias = small;
+      small = l as u32;
+    }
+  }
+  // Anything left over is only off from 1 by rounding, so it keeps itself.
+  for mut list in [small, large] {
+    while list != ALIAS_NONE {
+      let i = list as usize;
+      list = table[i].alias;
+      table[i] = AliasEntry { threshold: ALIAS_ONE, alias: i as u32 };
+    }
+  }
+  true
+}
@@ -0,0 +1,115 @@
+use super::*;
+
+/// The Zipf distribution, over the ranks `1 ..= n`, where rank `k` is chosen
+/// in proportion to `1 / k^s`.
+///
+/// * This models things like word frequencies and cache access patterns: a few
+///   items are very popular, and there's a long tail of rarely used ones.
+/// * An exponent of 0 gives a uniform choice, and bigger exponents make the top
+///   ranks more dominant.
+/// * Sampling uses the rejection-inversion method from W. Hörmann and G.
+///   Derflinger, "Rejection-inversion to generate variates from monotone
+///   discrete distributions" (1996). It takes about 1 uniform value per sample,
+///   no matter how large `n` is, and needs no tables.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Zipf {
+  n: u32,
+  s: f64,
+  h_integral_x1: f64,
+  h_integral_n: f64,
+  /// The "squeeze" that lets most samples skip the full rejection test.
+  squeeze: f64,
+}
+
+impl Zipf {
+  /// Constructs a new `Zipf`.
+  ///
+  /// ## Panics
+  /// * If `n` is 0.
+  /// * If `s` isn't finite, or is negative.
+  #[inline]
+  pub fn new(n: u32, s: f64) -> Self {
+    Self::try_new(n, s).expect("Zipf::new> Invalid parameters.")
+  }
+
+  /// Constructs a new `Zipf`, or `None` on failure.
+  ///
+  /// ## Failure
+  /// * If `n` is 0.
+  /// * If `s` isn't finite, or is negative.
+  #[inline]
+  pub fn try_new(n: u32, s: f64) -> Option<Self> {
+    if n == 0 || !(s.is_finite() && s >= 0.0) {
+      return None;
+    }
+    let h_integral_x1 = h_integral(1.5, s) - 1.0;
+    let h_integral_n = h_integral(n as f64 + 0.5, s);
+    let squeeze = 2.0 - h_integral_inv(h_integral(2.5, s) - h(2.0, s), s);
+    Some(Self { n, s, h_integral_x1, h_integral_n, squeeze })
+  }
+
+  /// The number of ranks.
+  #[inline]
+  pub const fn n(self) -> u32 {
+    self.n
+  }
+
+  /// The exponent.
+  #[inline]
+  pub const fn s(self) -> f64 {
+    self.s
+  }
+
+  /// Samples a rank in `1 ..= n`.
+  #[inline]
+  pub fn sample<G: Gen32 + ?Sized>(self, gen: &mut G) -> u32 {
+    loop {
+      let u = self.h_integral_n + gen.next_f64_unit() * (self.h_integral_x1 - self.h_integral_n);
+      let x = h_integral_inv(u, self.s);
+      let k = libm::floor(x + 0.5).max(1.0).min(self.n as f64);
+      if k - x <= self.squeeze || u >= h_integral(k + 0.5, self.s) - h(k, self.s) {
+        return k as u32;
+      }
+    }
+  }
+}
+
+/// The (unnormalized) weight of `x`, `x^-s`.
+#[inline]
+fn h(x: f64, s: f64) -> f64 {
+  libm::exp(-s * libm::log(x))
+}
+
+/// An integral of [`h`], `(x^(1-s) - 1) / (1 - s)`, or `ln(x)` when `s` is 1.
+#[inline]
+fn h_integral(x: f64, s: f64) -> f64 {
+  let log_x = libm::log(x);
+  expm1_over_x((1.0 - s) * log_x) * log_x
+}
+
+/// The inverse of [`h_integral`].
+#[inline]
+fn h_integral_inv(x: f64, s: f64) -> f64 {
+  let t = (x * (1.0 - s)).max(-1.0);
+  libm::exp(log1p_over_x(t) * x)
+}
+
+/// `ln(1 + x) / x`, accurate even when `x` is near 0.
+#[inline]
+fn log1p_over_x(x: f64) -> f64 {
+  if libm::fabs(x) > 1e-8 {
+    libm::log1p(x) / x
+  } else {
+    1.0 - x * (0.5 - x * (1.0 / 3.0 - 0.25 * x))
+  }
+}
+
+/// `(e^x - 1) / x`, accurate even when `x` is near 0.
+#[inline]
+fn expm1_over_x(x: f64) -> f64 {
+  if libm::fabs(x) > 1e-8 {
+    libm::expm1(x) / x
+  } else {
+    1.0 + x * 0.5 * (1.0 + x * (1.0 / 3.0) * (1.0 + 0.25 * x))
+  }
+}
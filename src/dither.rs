@@ -0,0 +1,73 @@
+use super::*;
+
+/// Rounds to a nearby integer at random, with the chance of rounding up being
+/// how far `x` is past the integer below it.
+///
+/// * `2.25` gives `3` one time in four, and `2` otherwise. On average the
+///   output is exactly `x`, so (unlike normal rounding) the error doesn't build
+///   up over many values, and a gradient doesn't turn into bands.
+/// * Values out of the `i32` range saturate, and NaN gives 0, the same as an
+///   `as` cast.
+#[inline]
+pub fn stochastic_round<G: Gen32 + ?Sized>(x: f32, gen: &mut G) -> i32 {
+  let floor = libm::floorf(x);
+  let frac = x - floor;
+  let up = gen.next_f32_unit() < frac;
+  (floor as i32).saturating_add(up as i32)
+}
+
+/// Converts an audio sample in `-1.0 ..= 1.0` to `i16`, with triangle (TPDF)
+/// dither.
+///
+/// * The sample is scaled by `i16::MAX`, then noise in `-1.0 .. 1.0` with a
+///   triangle shaped distribution is added before rounding. This turns the
+///   rounding error into a steady low noise floor, instead of distortion that
+///   follows the signal.
+/// * The result is clamped to the `i16` range, and NaN gives 0.
+#[inline]
+pub fn dither_round_f32_to_i16<G: Gen32 + ?Sized>(x: f32, gen: &mut G) -> i16 {
+  let r = gen.next_u32();
+  let tpdf = ((r >> 16) as i32 + (r & 0xFFFF) as i32 - 0xFFFF) as f32 * (1.0 / 65536.0);
+  let scaled = x * i16::MAX as f32 + tpdf;
+  // The `as` cast saturates, and turns NaN into 0.
+  libm::roundf(scaled) as i16
+}
+
+/// Lowers 8-bit values to `bits` bits, rounding each at random.
+///
+/// * Each value `x` becomes `x * (2^bits - 1) / 255` rounded up or down at
+///   random (as with [`stochastic_round`]). The output is in
+///   `0 ..= 2^bits - 1`, and on average each output is exactly the input
+///   scaled, so smooth gradients don't show bands.
+/// * For example, use 5 bits to turn 8-bit color channels into channels for
+///   RGB555 color.
+/// * This uses integer math only, with one bounded draw per value that isn't
+///   exactly on an output level.
+///
+/// ## Panics
+/// * If `bits` is 0 or more than 8.
+#[inline]
+pub fn dither_quantize<G: Gen32 + ?Sized>(buf: &mut [u8], bits: u32, gen: &mut G) {
+  assert!(bits > 0 && bits <= 8, "dither_quantize> Bits must be 1 to 8.");
+  let max = (1_u32 << bits) - 1;
+  for x in buf.iter_mut() {
+    let n = u32::from(*x) * max;
+    let (q, rem) = (n / 255, n % 255);
+    let up = rem != 0 && gen.next_bounded(255) < rem;
+    *x = (q + up as u32) as u8;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn dither_round_out_of_range() {
+    let mut gen = Pcg32::default();
+    assert_eq!(dither_round_f32_to_i16(f32::NAN, &mut gen), 0);
+    assert_eq!(dither_round_f32_to_i16(2.0, &mut gen), i16::MAX);
+    assert_eq!(dither_round_f32_to_i16(-2.0, &mut gen), i16::MIN);
+    assert_eq!(dither_round_f32_to_i16(f32::INFINITY, &mut gen), i16::MAX);
+  }
+}
@@ -0,0 +1,232 @@
+use super::*;
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+/// A deck of cards with a draw pile and a discard pile, stored in an array.
+///
+/// * Both piles share one array of `N` slots, so there can be at most `N` cards
+///   in the piles at once (cards that have been drawn and not yet discarded
+///   don't take up a slot).
+/// * When the draw pile runs out, call
+///   [`reshuffle_discards`](Self::reshuffle_discards) to shuffle the discards
+///   back in.
+/// * This needs no allocation. With the `std` feature there's also
+///   [`DrawPile`], which has no capacity limit.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ArrayDrawPile<T, const N: usize> {
+  /// The draw pile is `0 .. draw_len`, with the top card last. The discard
+  /// pile is `N - discard_len .. N`, with the top card first.
+  slots: [Option<T>; N],
+  draw_len: usize,
+  discard_len: usize,
+}
+
+impl<T, const N: usize> Default for ArrayDrawPile<T, N> {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<T, const N: usize> ArrayDrawPile<T, N> {
+  /// Makes an empty deck.
+  #[inline]
+  pub fn new() -> Self {
+    Self { slots: core::array::from_fn(|_| None), draw_len: 0, discard_len: 0 }
+  }
+
+  /// The number of cards in the draw pile.
+  #[inline]
+  pub const fn len(&self) -> usize {
+    self.draw_len
+  }
+
+  /// If the draw pile is empty.
+  #[inline]
+  pub const fn is_empty(&self) -> bool {
+    self.draw_len == 0
+  }
+
+  /// The number of cards in the discard pile.
+  #[inline]
+  pub const fn discard_len(&self) -> usize {
+    self.discard_len
+  }
+
+  /// The top card of the draw pile.
+  #[inline]
+  pub fn peek(&self) -> Option<&T> {
+    self.draw_len.checked_sub(1).and_then(|i| self.slots[i].as_ref())
+  }
+
+  /// The top card of the discard pile.
+  #[inline]
+  pub fn peek_discard(&self) -> Option<&T> {
+    if self.discard_len > 0 {
+      self.slots[N - self.discard_len].as_ref()
+    } else {
+      None
+    }
+  }
+
+  /// Puts a card into the draw pile at a random position.
+  ///
+  /// * The other cards in the draw pile keep their order.
+  ///
+  /// ## Failure
+  /// * If the deck is full, the card is given back.
+  #[inline]
+  pub fn shuffle_in<G: Gen32 + ?Sized>(&mut self, card: T, gen: &mut G) -> Result<(), T> {
+    if self.draw_len + self.discard_len >= N {
+      return Err(card);
+    }
+    self.slots[self.draw_len] = Some(card);
+    let i = gen.next_bounded(self.draw_len as u32 + 1) as usize;
+    self.slots[i..=self.draw_len].rotate_right(1);
+    self.draw_len += 1;
+    Ok(())
+  }
+
+  /// Shuffles the draw pile.
+  #[inline]
+  pub fn shuffle<G: Gen32>(&mut self, gen: &mut G) {
+    gen.shuffle(&mut self.slots[..self.draw_len]);
+  }
+
+  /// Draws the top card of the draw pile.
+  #[inline]
+  pub fn draw(&mut self) -> Option<T> {
+    self.draw_len = self.draw_len.checked_sub(1)?;
+    self.slots[self.draw_len].take()
+  }
+
+  /// Puts a card on top of the discard pile.
+  ///
+  /// ## Failure
+  /// * If the deck is full, the card is given back.
+  #[inline]
+  pub fn discard(&mut self, card: T) -> Result<(), T> {
+    if self.draw_len + self.discard_len >= N {
+      return Err(card);
+    }
+    self.discard_len += 1;
+    self.slots[N - self.discard_len] = Some(card);
+    Ok(())
+  }
+
+  /// Shuffles the discard pile and puts it under the draw pile.
+  ///
+  /// Any cards still in the draw pile stay on top, in the same order.
+  #[inline]
+  pub fn reshuffle_discards<G: Gen32>(&mut self, gen: &mut G) {
+    let (d, x) = (self.draw_len, self.discard_len);
+    if x == 0 {
+      return;
+    }
+    for i in 0..x {
+      self.slots.swap(d + i, N - x + i);
+    }
+    gen.shuffle(&mut self.slots[d..d + x]);
+    self.slots[..d + x].rotate_left(d);
+    self.draw_len = d + x;
+    self.discard_len = 0;
+  }
+}
+
+/// A deck of cards with a draw pile and a discard pile.
+///
+/// * This works like [`ArrayDrawPile`], but keeps the piles in `Vec`s, so
+///   there's no capacity limit. It's only available with the `std` feature.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct DrawPile<T> {
+  /// The top card is last.
+  draw: Vec<T>,
+  /// The top card is last.
+  discards: Vec<T>,
+}
+
+#[cfg(feature = "std")]
+impl<T> DrawPile<T> {
+  /// Makes an empty deck.
+  #[inline]
+  pub const fn new() -> Self {
+    Self { draw: Vec::new(), discards: Vec::new() }
+  }
+
+  /// Makes a deck with the given cards as the draw pile (in the order given,
+  /// so the last card is on top).
+  #[inline]
+  pub fn from_cards(cards: Vec<T>) -> Self {
+    Self { draw: cards, discards: Vec::new() }
+  }
+
+  /// The number of cards in the draw pile.
+  #[inline]
+  pub fn len(&self) -> usize {
+    self.draw.len()
+  }
+
+  /// If the draw pile is empty.
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.draw.is_empty()
+  }
+
+  /// The number of cards in the discard pile.
+  #[inline]
+  pub fn discard_len(&self) -> usize {
+    self.discards.len()
+  }
+
+  /// The top card of the draw pile.
+  #[inline]
+  pub fn peek(&self) -> Option<&T> {
+    self.draw.last()
+  }
+
+  /// The top card of the discard pile.
+  #[inline]
+  pub fn peek_discard(&self) -> Option<&T> {
+    self.discards.last()
+  }
+
+  /// Puts a card into the draw pile at a random position.
+  ///
+  /// * The other cards in the draw pile keep their order.
+  #[inline]
+  pub fn shuffle_in<G: Gen32 + ?Sized>(&mut self, card: T, gen: &mut G) {
+    let i = next_bounded_u64(gen, self.draw.len() as u64 + 1) as usize;
+    self.draw.insert(i, card);
+  }
+
+  /// Shuffles the draw pile.
+  #[inline]
+  pub fn shuffle<G: Gen32>(&mut self, gen: &mut G) {
+    gen.shuffle(&mut self.draw);
+  }
+
+  /// Draws the top card of the draw pile.
+  #[inline]
+  pub fn draw(&mut self) -> Option<T> {
+    self.draw.pop()
+  }
+
+  /// Puts a card on top of the discard pile.
+  #[inline]
+  pub fn discard(&mut self, card: T) {
+    self.discards.push(card);
+  }
+
+  /// Shuffles the discard pile and puts it under the draw pile.
+  ///
+  /// Any cards still in the draw pile stay on top, in the same order.
+  #[inline]
+  pub fn reshuffle_discards<G: Gen32>(&mut self, gen: &mut G) {
+    if self.discards.is_empty() {
+      return;
+    }
+    gen.shuffle(&mut self.discards);
+    self.draw.splice(0..0, self.discards.drain(..));
+  }
+}
@@ -0,0 +1,97 @@
+use super::*;
+
+/// Paces random events (such as encounters) so that the chance goes up with
+/// each step and resets when an event triggers.
+///
+/// * Steps are counted from 1 after the last event.
+/// * No event can happen before step `min_steps`, and an event is certain on
+///   step `max_steps`.
+/// * Between the two the chance goes up by a fixed amount each step. By default
+///   it ramps evenly from the minimum to the maximum, but you can pick your own
+///   starting chance and ramp with [`with_ramp`](Self::with_ramp).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EncounterClock {
+  #[cfg_attr(feature = "serde", serde(rename = "min_steps_v1"))]
+  min_steps: u32,
+  #[cfg_attr(feature = "serde", serde(rename = "max_steps_v1"))]
+  max_steps: u32,
+  #[cfg_attr(feature = "serde", serde(rename = "start_chance_v1"))]
+  start_chance: f32,
+  #[cfg_attr(feature = "serde", serde(rename = "per_step_v1"))]
+  per_step: f32,
+  #[cfg_attr(feature = "serde", serde(rename = "steps_v1"))]
+  steps: u32,
+}
+
+impl EncounterClock {
+  /// Makes a new clock.
+  ///
+  /// ## Panics
+  /// * If `min_steps` is more than `max_steps`.
+  #[inline]
+  pub fn new(min_steps: u32, max_steps: u32) -> Self {
+    Self::try_new(min_steps, max_steps).expect("EncounterClock::new> Invalid step bounds.")
+  }
+
+  /// Makes a new clock, or `None` on failure.
+  ///
+  /// ## Failure
+  /// * If `min_steps` is more than `max_steps`.
+  #[inline]
+  pub fn try_new(min_steps: u32, max_steps: u32) -> Option<Self> {
+    if min_steps > max_steps {
+      return None;
+    }
+    let chance = 1.0 / ((max_steps - min_steps) as f32 + 1.0);
+    Some(Self { min_steps, max_steps, start_chance: chance, per_step: chance, steps: 0 })
+  }
+
+  /// Sets the chance on step `min_steps`, and how much it goes up each step
+  /// after that.
+  ///
+  /// * Chances are clamped to `0.0 ..= 1.0` when used, and step `max_steps`
+  ///   always triggers no matter what.
+  #[inline]
+  pub fn with_ramp(self, start_chance: f32, per_step: f32) -> Self {
+    Self { start_chance, per_step, ..self }
+  }
+
+  /// The number of steps taken since the last event.
+  #[inline]
+  pub const fn steps(&self) -> u32 {
+    self.steps
+  }
+
+  /// Forgets all steps, as if an event had just happened.
+  #[inline]
+  pub fn reset(&mut self) {
+    self.steps = 0;
+  }
+
+  /// The chance that the next step triggers an event.
+  #[inline]
+  pub fn current_chance(&self) -> f32 {
+    let n = self.steps.saturating_add(1);
+    if n >= self.max_steps {
+      1.0
+    } else if n < self.min_steps {
+      0.0
+    } else {
+      let chance = self.start_chance + self.per_step * (n - self.min_steps) as f32;
+      chance.clamp(0.0, 1.0)
+    }
+  }
+
+  /// Takes a step, giving if an event triggers.
+  #[inline]
+  pub fn step<G: Gen32 + ?Sized>(&mut self, gen: &mut G) -> bool {
+    if gen.next_f32_unit() < self.current_chance() {
+      self.steps = 0;
+      true
+    } else {
+      self.steps = self.steps.saturating_add(1);
+      false
+    }
+  }
+}
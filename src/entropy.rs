@@ -0,0 +1,75 @@
+use super::*;
+
+/// Reads a little-endian `u32` out of a byte array.
+#[inline(always)]
+fn u32_at<const N: usize>(bytes: &[u8; N], i: usize) -> u32 {
+  let mut out = [0_u8; 4];
+  out.copy_from_slice(&bytes[i * 4..(i + 1) * 4]);
+  u32::from_le_bytes(out)
+}
+
+/// Reads a little-endian `u64` out of a byte array.
+#[inline(always)]
+fn u64_at<const N: usize>(bytes: &[u8; N], i: usize) -> u64 {
+  let mut out = [0_u8; 8];
+  out.copy_from_slice(&bytes[i * 8..(i + 1) * 8]);
+  u64::from_le_bytes(out)
+}
+
+/// Reads a little-endian `u128` out of a byte array.
+#[inline(always)]
+fn u128_at<const N: usize>(bytes: &[u8; N], i: usize) -> u128 {
+  let mut out = [0_u8; 16];
+  out.copy_from_slice(&bytes[i * 16..(i + 1) * 16]);
+  u128::from_le_bytes(out)
+}
+
+/// Implements `from_entropy` for generators, given how many bytes to read and
+/// how to build the generator out of them.
+macro_rules! impl_from_entropy {
+  ($($t:ty, $n:literal, |$b:ident| $e:expr;)*) => {
+    $(
+      impl $t {
+        /// Seed a new generator from the operating system's entropy source.
+        ///
+        /// ## Failure
+        /// * If the OS can't provide entropy, see `getrandom::fill`.
+        #[inline]
+        pub fn from_entropy() -> Result<Self, getrandom::Error> {
+          let mut $b = [0_u8; $n];
+          getrandom::fill(&mut $b)?;
+          Ok($e)
+        }
+      }
+    )*
+  };
+}
+
+impl_from_entropy! {
+  Pcg32, 16, |b| Pcg32::seed(u64_at(&b, 0), u64_at(&b, 1));
+  RNG, 8, |b| RNG::seed(u32_at(&b, 0), u32_at(&b, 1));
+  Pcg64, 32, |b| Pcg64::seed(u128_at(&b, 0), u128_at(&b, 1));
+  Xoshiro128pp, 16, |b| Xoshiro128pp::seed([u32_at(&b, 0), u32_at(&b, 1), u32_at(&b, 2), u32_at(&b, 3)]);
+  Xoshiro256pp, 32, |b| Xoshiro256pp::seed([u64_at(&b, 0), u64_at(&b, 1), u64_at(&b, 2), u64_at(&b, 3)]);
+  SplitMix64, 8, |b| SplitMix64::seed(u64_at(&b, 0));
+  WyRand, 8, |b| WyRand::seed(u64_at(&b, 0));
+  Sfc32, 12, |b| Sfc32::seed(u32_at(&b, 0), u32_at(&b, 1), u32_at(&b, 2));
+  Jsf32, 4, |b| Jsf32::seed(u32_at(&b, 0));
+  ChaCha8, 40, |b| ChaCha8::seed(core::array::from_fn(|i| u32_at(&b, i)), u64_at(&b, 4));
+  ChaCha20Rng, 40, |b| ChaCha20Rng::seed(core::array::from_fn(|i| u32_at(&b, i)), u64_at(&b, 4));
+  RomuTrio, 8, |b| RomuTrio::seed(u64_at(&b, 0));
+  RomuQuad, 8, |b| RomuQuad::seed(u64_at(&b, 0));
+  RomuTrio32, 8, |b| RomuTrio32::seed(u64_at(&b, 0));
+  Lehmer128, 16, |b| Lehmer128::seed(u128_at(&b, 0));
+  Mt19937, 2496, |b| Mt19937::seed_from_slice(&core::array::from_fn::<u32, 624, _>(|i| u32_at(&b, i)));
+  Isaac32, 1024, |b| Isaac32::seed_from_slice(&core::array::from_fn::<u32, 256, _>(|i| u32_at(&b, i)));
+  Philox4x32, 8, |b| Philox4x32::seed(u64_at(&b, 0), 0);
+  Threefry2x64, 16, |b| Threefry2x64::seed(u128_at(&b, 0), 0);
+  Threefry4x32, 16, |b| Threefry4x32::seed(u128_at(&b, 0), 0);
+  Msws, 16, |b| Msws::seed(u64_at(&b, 0), u64_at(&b, 1));
+  XorShift128, 16, |b| XorShift128::seed([u32_at(&b, 0), u32_at(&b, 1), u32_at(&b, 2), u32_at(&b, 3)]);
+  Mwc32, 8, |b| Mwc32::seed(u32_at(&b, 0), u32_at(&b, 1));
+  Cmwc<4096>, 8, |b| Cmwc::<4096>::seed(u64_at(&b, 0));
+  Well512, 8, |b| Well512::seed(u64_at(&b, 0));
+  TinyMt32, 4, |b| TinyMt32::seed(u32_at(&b, 0));
+}
@@ -0,0 +1,103 @@
+use super::*;
+
+/// `TM0CNT_L` through `TM3CNT_L`, the counter of each timer.
+const TIMER_COUNTERS: [*const u16; 4] = [
+  0x0400_0100 as *const u16,
+  0x0400_0104 as *const u16,
+  0x0400_0108 as *const u16,
+  0x0400_010C as *const u16,
+];
+
+/// The current scanline.
+const VCOUNT: *const u16 = 0x0400_0006 as *const u16;
+
+/// The current key state (active low).
+const KEYINPUT: *const u16 = 0x0400_0130 as *const u16;
+
+/// Gathers entropy on the GBA, to seed a generator with.
+///
+/// The GBA has no hardware RNG, and every boot starts from the exact same
+/// state. The only thing that really varies between runs is the player, so
+/// this pool mixes in the timing of their key presses along with the timer
+/// counters and the scanline position.
+///
+/// * Call [`sample`](Self::sample) once per frame, starting from the title
+///   screen or wherever the game first waits for input.
+/// * Each change in the key state mixes in the number of frames since the pool
+///   was made, which is where most of the entropy comes from. Timers that are
+///   running (at any prescaler) add some jitter too.
+/// * When the player finally presses Start (or similar), call
+///   [`finalize`](Self::finalize) and pass the output to [`Pcg32::seed`].
+/// * You can also mix in any other values you have on hand with
+///   [`mix`](Self::mix).
+/// * The `sample` method reads the GBA's IO registers directly, so it's
+///   `unsafe`: it must only be used when actually running on a GBA.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EntropyPool {
+  state: [u64; 2],
+  frames: u32,
+  last_keys: u16,
+}
+
+impl EntropyPool {
+  /// Makes a new, empty pool.
+  pub const fn new() -> Self {
+    Self { state: [DEFAULT_PCG_SEED as u64, DEFAULT_PCG_INC as u64], frames: 0, last_keys: 0x03FF }
+  }
+
+  /// Mixes a value into the pool.
+  #[inline]
+  pub fn mix(&mut self, value: u32) {
+    let [a, b] = self.state;
+    let a = splitmix64_mix(a ^ (value as u64)).wrapping_add(b);
+    let b = b.rotate_left(23) ^ a;
+    self.state = [a, b];
+  }
+
+  /// Reads the timers, `VCOUNT`, and the keys, and mixes them into the pool.
+  ///
+  /// Call this once per frame.
+  ///
+  /// ## Safety
+  /// * This reads fixed GBA IO register addresses, so it must only be called
+  ///   when running on a GBA. On any other device those addresses can be
+  ///   anything at all.
+  #[inline]
+  pub unsafe fn sample(&mut self) {
+    // Safety: the caller promises that we're on a GBA, where these are all
+    // readable IO registers.
+    let (timers, vcount, keys) = unsafe {
+      let mut timers = 0_u64;
+      for t in TIMER_COUNTERS.iter() {
+        timers = (timers << 16) | (t.read_volatile() as u64);
+      }
+      (timers, VCOUNT.read_volatile(), KEYINPUT.read_volatile())
+    };
+    self.mix(timers as u32);
+    self.mix((timers >> 32) as u32);
+    self.mix(vcount as u32);
+    if keys != self.last_keys {
+      self.mix(((keys as u32) << 16) ^ self.frames);
+      self.last_keys = keys;
+    }
+    self.frames = self.frames.wrapping_add(1);
+  }
+
+  /// Gets a seed from the pool, for use with [`Pcg32::seed`].
+  ///
+  /// This doesn't change the pool, so you can keep sampling and finalize again
+  /// later for another seed.
+  #[inline]
+  pub fn finalize(&self) -> (u64, u64) {
+    let [a, b] = self.state;
+    let x = splitmix64_mix(a ^ (self.frames as u64));
+    let y = splitmix64_mix(b.wrapping_add(x));
+    (x, y)
+  }
+}
+
+impl Default for EntropyPool {
+  fn default() -> Self {
+    Self::new()
+  }
+}
@@ -31,6 +31,44 @@ pub trait Gen32 {
     h << 32 | l
   }
 
+  /// Produce an `f32` in the range `0.0 .. 1.0`
+  #[inline]
+  fn next_f32_unit(&mut self) -> f32 {
+    (self.next_u32() >> 8) as f32 * (1.0 / (1_u32 << 24) as f32)
+  }
+
+  /// Produce an `f64` in the range `0.0 .. 1.0`
+  #[inline]
+  fn next_f64_unit(&mut self) -> f64 {
+    (self.next_u64() >> 11) as f64 * (1.0 / (1_u64 << 53) as f64)
+  }
+
+  /// Fills the slice with `u32` outputs, one per element.
+  #[inline]
+  fn fill_u32s(&mut self, buf: &mut [u32]) {
+    for x in buf.iter_mut() {
+      *x = self.next_u32();
+    }
+  }
+
+  /// Fills the slice with bytes.
+  ///
+  /// Each `u32` of output gives four bytes (little-endian order). If the
+  /// slice's length isn't a multiple of 4 then the tail takes the low bytes of
+  /// one final output and the rest of that output is discarded.
+  #[inline]
+  fn fill_bytes(&mut self, buf: &mut [u8]) {
+    let mut chunks = buf.chunks_exact_mut(4);
+    for chunk in &mut chunks {
+      chunk.copy_from_slice(&self.next_u32().to_le_bytes());
+    }
+    let tail = chunks.into_remainder();
+    if !tail.is_empty() {
+      let bytes = self.next_u32().to_le_bytes();
+      tail.copy_from_slice(&bytes[..tail.len()]);
+    }
+  }
+
   /// Gives a value within `0 .. B`
   ///
   /// This is often more efficient than making a
@@ -57,6 +95,22 @@ pub trait Gen32 {
     high
   }
 
+  /// Gives a value within the range given, such as `1 ..= 20` or
+  /// `0.0 .. 1.0`.
+  ///
+  /// * See [`SampleRange`](crate::SampleRange) for the supported ranges.
+  ///
+  /// ## Panics
+  /// * If the range is empty.
+  #[inline]
+  fn gen_range<T, R>(&mut self, range: R) -> T
+  where
+    Self: Sized,
+    R: crate::SampleRange<T>,
+  {
+    range.sample_range(self)
+  }
+
   /// Gets a value out of the slice given (by copy).
   ///
   /// * The default impl will not pick past index `u32::MAX`.
@@ -94,9 +148,46 @@ pub trait Gen32 {
     &mut buf[usize::try_from(self.next_bounded(end)).unwrap()]
   }
 
+  /// Gets a value out of the slice given (by copy), with a chance
+  /// proportional to its weight.
+  ///
+  /// * Item `i` gets weight `weights[i]`, and an item with a weight of 0 is
+  ///   never picked.
+  /// * This takes one bounded `u64` draw and a linear scan, with no setup, so
+  ///   it's good for one-off choices. To pick from the same weights many times,
+  ///   see [`WeightedAlias`](crate::WeightedAlias).
+  ///
+  /// ## Panics
+  /// * If the slices aren't the same length.
+  /// * If the weights add up to 0 (including if there are none).
+  #[inline]
+  fn pick_weighted<T>(&mut self, items: &[T], weights: &[u32]) -> T
+  where
+    Self: Sized,
+    T: Copy,
+  {
+    assert_eq!(
+      items.len(),
+      weights.len(),
+      "Gen32::pick_weighted> Items and weights must be the same length."
+    );
+    let total: u64 = weights.iter().map(|&w| w as u64).sum();
+    assert!(total != 0, "Gen32::pick_weighted> Weights must not add up to 0.");
+    let target = next_bounded_u64(self, total);
+    let mut running = 0_u64;
+    for (item, &w) in items.iter().zip(weights.iter()) {
+      running += w as u64;
+      if target < running {
+        return *item;
+      }
+    }
+    unreachable!()
+  }
+
   /// Shuffles a slice in `O(len)` time.
   ///
   /// * The default impl shuffles only the first `u32::MAX` elements.
+  /// * An empty slice is left as is.
   #[inline]
   fn shuffle<T>(&mut self, buf: &mut [T])
   where
@@ -108,7 +199,7 @@ pub trait Gen32 {
     // fetch-ahead of most modern CPUs.
     let mut possibility_count: u32 = buf.len().try_into().unwrap_or(u32::max_value());
     let mut this_index: usize = 0;
-    let end = buf.len() - 1;
+    let end = buf.len().saturating_sub(1);
     while this_index < end {
       let offset = self.next_bounded(possibility_count) as usize;
       buf.swap(this_index, this_index + offset);
@@ -141,3 +232,19 @@ const fn saturating_usize_as_u32(val: usize) -> u32 {
     }
   }
 }
+
+/// Gets a value in `0 .. b` from a [`Gen32`], for a bound that might not fit in
+/// a `u32`.
+///
+/// This is Lemire's method, the same as [`Gen32::next_bounded`].
+#[inline]
+pub(crate) fn next_bounded_u64<G: Gen32 + ?Sized>(gen: &mut G, b: u64) -> u64 {
+  debug_assert!(b != 0);
+  let threshold = b.wrapping_neg() % b;
+  loop {
+    let mul = (gen.next_u64() as u128) * (b as u128);
+    if (mul as u64) >= threshold {
+      return (mul >> 64) as u64;
+    }
+  }
+}
@@ -0,0 +1,158 @@
+use super::*;
+use core::convert::TryFrom;
+
+/// A Generator with 64 bits of output per step.
+pub trait Gen64 {
+  /// Generates the next 64 bits of output.
+  fn next_u64(&mut self) -> u64;
+
+  /// Produce a `bool`
+  #[inline(always)]
+  fn next_bool(&mut self) -> bool {
+    (self.next_u64() as i64) < 0
+  }
+
+  /// Produce a `u8`
+  #[inline(always)]
+  fn next_u8(&mut self) -> u8 {
+    (self.next_u64() >> 56) as u8
+  }
+
+  /// Produce a `u16`
+  #[inline(always)]
+  fn next_u16(&mut self) -> u16 {
+    (self.next_u64() >> 48) as u16
+  }
+
+  /// Produce a `u32`
+  #[inline(always)]
+  fn next_u32(&mut self) -> u32 {
+    (self.next_u64() >> 32) as u32
+  }
+
+  /// Produce an `f32` in the range `0.0 .. 1.0`
+  #[inline]
+  fn next_f32_unit(&mut self) -> f32 {
+    (self.next_u64() >> 40) as f32 * (1.0 / (1_u32 << 24) as f32)
+  }
+
+  /// Produce an `f64` in the range `0.0 .. 1.0`
+  #[inline]
+  fn next_f64_unit(&mut self) -> f64 {
+    (self.next_u64() >> 11) as f64 * (1.0 / (1_u64 << 53) as f64)
+  }
+
+  /// Gives a value within `0 .. B`
+  ///
+  /// ## Panics
+  /// * If the input is 0.
+  #[inline]
+  fn next_bounded(&mut self, b: u64) -> u64 {
+    assert!(b != 0, "Gen64::next_bounded> Bound must be non-zero.");
+    let mut x = self.next_u64() as u128;
+    let mut mul = (b as u128).wrapping_mul(x);
+    let mut low = mul as u64;
+    if low < b {
+      let threshold = b.wrapping_neg() % b;
+      while low < threshold {
+        x = self.next_u64() as u128;
+        mul = (b as u128).wrapping_mul(x);
+        low = mul as u64;
+      }
+    }
+    (mul >> 64) as u64
+  }
+
+  /// Gets a value out of the slice given (by copy).
+  #[inline(always)]
+  fn pick<T>(&mut self, buf: &[T]) -> T
+  where
+    Self: Sized,
+    T: Copy,
+  {
+    let end = buf.len() as u64;
+    buf[usize::try_from(self.next_bounded(end)).unwrap()]
+  }
+
+  /// Gets a value out of the slice given (by shared ref).
+  #[inline(always)]
+  fn pick_ref<'b, T>(&mut self, buf: &'b [T]) -> &'b T
+  where
+    Self: Sized,
+  {
+    let end = buf.len() as u64;
+    &buf[usize::try_from(self.next_bounded(end)).unwrap()]
+  }
+
+  /// Gets a value out of the slice given (by unique ref).
+  #[inline(always)]
+  fn pick_mut<'b, T>(&mut self, buf: &'b mut [T]) -> &'b mut T
+  where
+    Self: Sized,
+  {
+    let end = buf.len() as u64;
+    &mut buf[usize::try_from(self.next_bounded(end)).unwrap()]
+  }
+
+  /// Shuffles a slice in `O(len)` time.
+  ///
+  /// This walks forward through the slice, the same as [`Gen32::shuffle`].
+  #[inline]
+  fn shuffle<T>(&mut self, buf: &mut [T])
+  where
+    Self: Sized,
+  {
+    let mut possibility_count = buf.len() as u64;
+    let mut this_index: usize = 0;
+    let end = buf.len().saturating_sub(1);
+    while this_index < end {
+      let offset = self.next_bounded(possibility_count) as usize;
+      buf.swap(this_index, this_index + offset);
+      possibility_count -= 1;
+      this_index += 1;
+    }
+  }
+}
+
+// Asserts that `Gen64` is an object-safe trait.
+const _: [&mut dyn Gen64; 0] = [];
+
+/// Adapts any [`Gen32`] into a [`Gen64`].
+///
+/// Each 64-bit output is built from two 32-bit outputs of the inner generator,
+/// exactly as [`Gen32::next_u64`] does.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct Gen32As64<G>(pub G);
+
+impl<G: Gen32> Gen64 for Gen32As64<G> {
+  #[inline(always)]
+  fn next_u64(&mut self) -> u64 {
+    self.0.next_u64()
+  }
+
+  #[inline(always)]
+  fn next_u32(&mut self) -> u32 {
+    self.0.next_u32()
+  }
+}
+
+/// Adapts any [`Gen64`] into a [`Gen32`].
+///
+/// Each 32-bit output is the high half of one 64-bit output of the inner
+/// generator, and `next_u64` passes straight through.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct Gen64As32<G>(pub G);
+
+impl<G: Gen64> Gen32 for Gen64As32<G> {
+  #[inline(always)]
+  fn next_u32(&mut self) -> u32 {
+    self.0.next_u32()
+  }
+
+  #[inline(always)]
+  fn next_u64(&mut self) -> u64 {
+    self.0.next_u64()
+  }
+}
@@ -0,0 +1,292 @@
+use super::*;
+use core::f32::consts::TAU;
+
+/// Geometric sampling helpers, for any [`Gen32`].
+///
+/// * This is implemented for every generator, so just bring the trait into
+///   scope and call `gen.unit_circle_point()` and so on.
+/// * All points are plain `f32` tuples and arrays, so no math crate is needed.
+pub trait Geometry: Gen32 {
+  /// A random angle in radians, from `0.0` up to `TAU`.
+  #[inline]
+  fn unit_circle_angle(&mut self) -> f32 {
+    self.next_f32_unit() * TAU
+  }
+
+  /// A random point on the unit circle, as `(x, y)`.
+  ///
+  /// Every direction is equally likely, so this is a random 2D direction.
+  #[inline]
+  fn unit_circle_point(&mut self) -> (f32, f32) {
+    let (sin, cos) = libm::sincosf(self.unit_circle_angle());
+    (cos, sin)
+  }
+
+  /// A random point inside the unit disk, as `(x, y)`.
+  ///
+  /// * Every point in the disk is equally likely. The radius is the square root
+  ///   of a uniform value, so that points aren't bunched up in the middle (as
+  ///   they would be with a uniform radius).
+  /// * This always takes two `u32` values. See
+  ///   [`in_unit_disk_rejection`](Self::in_unit_disk_rejection) for a version
+  ///   that needs no square root or trig.
+  #[inline]
+  fn in_unit_disk(&mut self) -> (f32, f32) {
+    let r = libm::sqrtf(self.next_f32_unit());
+    let (x, y) = self.unit_circle_point();
+    (x * r, y * r)
+  }
+
+  /// A random point inside the unit disk, as `(x, y)`, using rejection.
+  ///
+  /// * Points are picked in the square around the disk until one lands inside
+  ///   it. This takes about 1.27 tries on average, and uses only basic math, so
+  ///   it's often faster on targets without a fast `sqrt` or `sin`.
+  #[inline]
+  fn in_unit_disk_rejection(&mut self) -> (f32, f32) {
+    loop {
+      let x = signed_unit_f32(self);
+      let y = signed_unit_f32(self);
+      if x * x + y * y < 1.0 {
+        return (x, y);
+      }
+    }
+  }
+
+  /// A random point on the unit sphere, as `[x, y, z]`.
+  ///
+  /// * Every direction is equally likely, so this is a random 3D direction.
+  /// * Uses Marsaglia's method: a point is picked in the unit disk (by
+  ///   rejection) and then mapped onto the sphere.
+  #[inline]
+  fn unit_sphere_point(&mut self) -> [f32; 3] {
+    let (u, v) = self.in_unit_disk_rejection();
+    let s = u * u + v * v;
+    let k = 2.0 * libm::sqrtf(1.0 - s);
+    [u * k, v * k, 1.0 - 2.0 * s]
+  }
+
+  /// A random point inside the unit ball, as `[x, y, z]`.
+  ///
+  /// * Every point in the ball is equally likely. The radius is the cube root
+  ///   of a uniform value, so that points aren't bunched up in the middle.
+  /// * See [`in_unit_ball_rejection`](Self::in_unit_ball_rejection) for a
+  ///   version that needs no cube root.
+  #[inline]
+  fn in_unit_ball(&mut self) -> [f32; 3] {
+    let r = libm::cbrtf(self.next_f32_unit());
+    let [x, y, z] = self.unit_sphere_point();
+    [x * r, y * r, z * r]
+  }
+
+  /// A random point inside the unit ball, as `[x, y, z]`, using rejection.
+  ///
+  /// * Points are picked in the cube around the ball until one lands inside it.
+  ///   This takes about 1.91 tries on average, and uses only basic math.
+  #[inline]
+  fn in_unit_ball_rejection(&mut self) -> [f32; 3] {
+    loop {
+      let x = signed_unit_f32(self);
+      let y = signed_unit_f32(self);
+      let z = signed_unit_f32(self);
+      if x * x + y * y + z * z < 1.0 {
+        return [x, y, z];
+      }
+    }
+  }
+
+  /// A random unit vector in the hemisphere around `+z`, with a cosine
+  /// weighting.
+  ///
+  /// * Directions are more likely the closer they are to `+z`, in proportion to
+  ///   the cosine of the angle, which is the ideal diffuse reflection from a
+  ///   surface with normal `+z`.
+  /// * The output always has `z >= 0.0`.
+  #[inline]
+  fn cosine_hemisphere_point(&mut self) -> [f32; 3] {
+    // Malley's method: a uniform point in the disk, projected up.
+    let (x, y) = self.in_unit_disk_rejection();
+    [x, y, libm::sqrtf(1.0 - (x * x + y * y))]
+  }
+
+  /// A random point on the unit sphere in `N` dimensions.
+  ///
+  /// * Every direction is equally likely, so this is a random `N` dimensional
+  ///   direction.
+  /// * Each axis is a standard normal value, and then the whole point is
+  ///   normalized. The math is done in `f64`.
+  /// * For 2 or 3 dimensions, [`unit_circle_point`](Self::unit_circle_point)
+  ///   and [`unit_sphere_point`](Self::unit_sphere_point) are faster.
+  ///
+  /// ## Panics
+  /// * If `N` is 0.
+  #[inline]
+  fn on_n_sphere<const N: usize>(&mut self) -> [f32; N] {
+    assert!(N > 0, "Geometry::on_n_sphere> Must have at least one dimension.");
+    let mut v = [0.0_f64; N];
+    let len_sq = loop {
+      v.iter_mut().for_each(|x| *x = standard_normal(self));
+      let len_sq: f64 = v.iter().map(|x| x * x).sum();
+      // This is almost never 0, but it can be.
+      if len_sq > 0.0 {
+        break len_sq;
+      }
+    };
+    let inv_len = 1.0 / libm::sqrt(len_sq);
+    let mut out = [0.0; N];
+    for (o, x) in out.iter_mut().zip(v.iter()) {
+      *o = (x * inv_len) as f32;
+    }
+    out
+  }
+
+  /// A random unit quaternion, as `[x, y, z, w]`.
+  ///
+  /// * Every orientation is equally likely, so this is a random 3D rotation.
+  /// * Uses Shoemake's method, which takes exactly three `u32` values.
+  #[inline]
+  fn unit_quaternion(&mut self) -> [f32; 4] {
+    let u = self.next_f32_unit();
+    let a = libm::sqrtf(1.0 - u);
+    let b = libm::sqrtf(u);
+    let (sin1, cos1) = libm::sincosf(self.unit_circle_angle());
+    let (sin2, cos2) = libm::sincosf(self.unit_circle_angle());
+    [a * sin1, a * cos1, b * sin2, b * cos2]
+  }
+
+  /// A random 2D rotation matrix.
+  ///
+  /// * The matrix is given as two columns, `[[cos, sin], [-sin, cos]]`, for an
+  ///   angle picked with [`unit_circle_angle`](Self::unit_circle_angle).
+  #[inline]
+  fn rotation_2d(&mut self) -> [[f32; 2]; 2] {
+    let (sin, cos) = libm::sincosf(self.unit_circle_angle());
+    [[cos, sin], [-sin, cos]]
+  }
+
+  /// A random 3D rotation matrix.
+  ///
+  /// * The matrix is given as three columns, which are also a random
+  ///   orthonormal basis (right handed).
+  /// * Every orientation is equally likely. This is the matrix form of
+  ///   [`unit_quaternion`](Self::unit_quaternion).
+  #[inline]
+  fn rotation_3d(&mut self) -> [[f32; 3]; 3] {
+    let [x, y, z, w] = self.unit_quaternion();
+    [
+      [1.0 - 2.0 * (y * y + z * z), 2.0 * (x * y + w * z), 2.0 * (x * z - w * y)],
+      [2.0 * (x * y - w * z), 1.0 - 2.0 * (x * x + z * z), 2.0 * (y * z + w * x)],
+      [2.0 * (x * z + w * y), 2.0 * (y * z - w * x), 1.0 - 2.0 * (x * x + y * y)],
+    ]
+  }
+
+  /// A random point inside the triangle with corners `a`, `b`, and `c`.
+  ///
+  /// * Works in any number of dimensions, though usually that's `[f32; 2]` or
+  ///   `[f32; 3]`.
+  /// * Every point in the triangle is equally likely. The first barycentric
+  ///   weight uses a square root, since just lerping between random points on
+  ///   the edges bunches points up toward one corner.
+  #[inline]
+  fn in_triangle<const N: usize>(&mut self, a: [f32; N], b: [f32; N], c: [f32; N]) -> [f32; N] {
+    let r1 = libm::sqrtf(self.next_f32_unit());
+    let r2 = self.next_f32_unit();
+    let (wa, wb, wc) = (1.0 - r1, r1 * (1.0 - r2), r1 * r2);
+    let mut out = [0.0; N];
+    for (i, o) in out.iter_mut().enumerate() {
+      *o = wa * a[i] + wb * b[i] + wc * c[i];
+    }
+    out
+  }
+
+  /// A random point in the rectangle from `min` to `max`.
+  ///
+  /// * Each axis is uniform from its `min` up to its `max`.
+  #[inline]
+  fn in_rect(&mut self, min: [f32; 2], max: [f32; 2]) -> [f32; 2] {
+    in_aabb(self, min, max)
+  }
+
+  /// A random point in the box from `min` to `max`.
+  ///
+  /// * Each axis is uniform from its `min` up to its `max`.
+  #[inline]
+  fn in_box(&mut self, min: [f32; 3], max: [f32; 3]) -> [f32; 3] {
+    in_aabb(self, min, max)
+  }
+
+  /// A random point on the integer grid in `min .. max` (so `max` is
+  /// excluded on each axis).
+  ///
+  /// * Good for picking a tile or pixel, eg: `gen.in_grid_rect([0, 0], [240,
+  ///   160])` for a spot on the GBA screen.
+  /// * This is a single bounded draw over the whole area, not one per axis.
+  ///
+  /// ## Panics
+  /// * If `max` isn't more than `min` on each axis.
+  #[inline]
+  fn in_grid_rect(&mut self, min: [i32; 2], max: [i32; 2]) -> [i32; 2] {
+    in_grid(self, min, max)
+  }
+
+  /// A random point on the integer grid in `min .. max` (so `max` is
+  /// excluded on each axis).
+  ///
+  /// * This is a single bounded draw over the whole volume (unless it's more
+  ///   than `u64::MAX` points, then it's one per axis).
+  ///
+  /// ## Panics
+  /// * If `max` isn't more than `min` on each axis.
+  #[inline]
+  fn in_grid_box(&mut self, min: [i32; 3], max: [i32; 3]) -> [i32; 3] {
+    in_grid(self, min, max)
+  }
+}
+
+impl<G: Gen32 + ?Sized> Geometry for G {}
+
+/// An `f32` in `-1.0 .. 1.0`.
+#[inline]
+fn signed_unit_f32<G: Gen32 + ?Sized>(gen: &mut G) -> f32 {
+  gen.next_f32_unit() * 2.0 - 1.0
+}
+
+/// A uniform point in an axis aligned bounding box.
+#[inline]
+fn in_aabb<G: Gen32 + ?Sized, const N: usize>(
+  gen: &mut G, min: [f32; N], max: [f32; N],
+) -> [f32; N] {
+  let mut out = [0.0; N];
+  for (i, o) in out.iter_mut().enumerate() {
+    *o = min[i] + (max[i] - min[i]) * gen.next_f32_unit();
+  }
+  out
+}
+
+/// A uniform point on the integer grid in `min .. max`.
+#[inline]
+fn in_grid<G: Gen32 + ?Sized, const N: usize>(
+  gen: &mut G, min: [i32; N], max: [i32; N],
+) -> [i32; N] {
+  let mut sizes = [0_u64; N];
+  for (i, size) in sizes.iter_mut().enumerate() {
+    assert!(min[i] < max[i], "Geometry> The max must be more than the min on each axis.");
+    *size = (max[i] as i64 - min[i] as i64) as u64;
+  }
+  let mut out = min;
+  match sizes.iter().try_fold(1_u64, |total, &size| total.checked_mul(size)) {
+    Some(total) => {
+      let mut r = next_bounded_u64(gen, total);
+      for (o, size) in out.iter_mut().zip(sizes.iter()) {
+        *o = (*o as i64 + (r % size) as i64) as i32;
+        r /= size;
+      }
+    }
+    None => {
+      for (o, size) in out.iter_mut().zip(sizes.iter()) {
+        *o = (*o as i64 + next_bounded_u64(gen, *size) as i64) as i32;
+      }
+    }
+  }
+  out
+}
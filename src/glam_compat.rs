@@ -0,0 +1,96 @@
+use super::*;
+use glam::{IVec2, IVec3, Mat2, Mat3, Quat, Vec2, Vec3};
+
+/// [`Geometry`] helpers that use `glam` types, for any [`Gen32`].
+///
+/// * Each method is the same as the array based [`Geometry`] method of the same
+///   name without the type suffix, so see there for the details.
+pub trait GlamGeometry: Geometry {
+  /// See [`unit_circle_point`](Geometry::unit_circle_point).
+  #[inline]
+  fn unit_vec2(&mut self) -> Vec2 {
+    let (x, y) = self.unit_circle_point();
+    Vec2::new(x, y)
+  }
+
+  /// See [`unit_sphere_point`](Geometry::unit_sphere_point).
+  #[inline]
+  fn unit_vec3(&mut self) -> Vec3 {
+    Vec3::from_array(self.unit_sphere_point())
+  }
+
+  /// See [`cosine_hemisphere_point`](Geometry::cosine_hemisphere_point).
+  #[inline]
+  fn cosine_hemisphere_vec3(&mut self) -> Vec3 {
+    Vec3::from_array(self.cosine_hemisphere_point())
+  }
+
+  /// See [`in_unit_disk`](Geometry::in_unit_disk).
+  #[inline]
+  fn in_unit_disk_vec2(&mut self) -> Vec2 {
+    let (x, y) = self.in_unit_disk();
+    Vec2::new(x, y)
+  }
+
+  /// See [`in_unit_ball`](Geometry::in_unit_ball).
+  #[inline]
+  fn in_unit_ball_vec3(&mut self) -> Vec3 {
+    Vec3::from_array(self.in_unit_ball())
+  }
+
+  /// See [`in_rect`](Geometry::in_rect).
+  #[inline]
+  fn in_rect_vec2(&mut self, min: Vec2, max: Vec2) -> Vec2 {
+    Vec2::from_array(self.in_rect(min.to_array(), max.to_array()))
+  }
+
+  /// See [`in_box`](Geometry::in_box).
+  #[inline]
+  fn in_box_vec3(&mut self, min: Vec3, max: Vec3) -> Vec3 {
+    Vec3::from_array(self.in_box(min.to_array(), max.to_array()))
+  }
+
+  /// See [`in_grid_rect`](Geometry::in_grid_rect).
+  ///
+  /// ## Panics
+  /// * If `max` isn't more than `min` on each axis.
+  #[inline]
+  fn in_grid_rect_ivec2(&mut self, min: IVec2, max: IVec2) -> IVec2 {
+    IVec2::from_array(self.in_grid_rect(min.to_array(), max.to_array()))
+  }
+
+  /// See [`in_grid_box`](Geometry::in_grid_box).
+  ///
+  /// ## Panics
+  /// * If `max` isn't more than `min` on each axis.
+  #[inline]
+  fn in_grid_box_ivec3(&mut self, min: IVec3, max: IVec3) -> IVec3 {
+    IVec3::from_array(self.in_grid_box(min.to_array(), max.to_array()))
+  }
+
+  /// See [`in_triangle`](Geometry::in_triangle).
+  #[inline]
+  fn in_triangle_vec3(&mut self, a: Vec3, b: Vec3, c: Vec3) -> Vec3 {
+    Vec3::from_array(self.in_triangle(a.to_array(), b.to_array(), c.to_array()))
+  }
+
+  /// See [`unit_quaternion`](Geometry::unit_quaternion).
+  #[inline]
+  fn unit_quat(&mut self) -> Quat {
+    Quat::from_array(self.unit_quaternion())
+  }
+
+  /// See [`rotation_2d`](Geometry::rotation_2d).
+  #[inline]
+  fn rotation_mat2(&mut self) -> Mat2 {
+    Mat2::from_cols_array_2d(&self.rotation_2d())
+  }
+
+  /// See [`rotation_3d`](Geometry::rotation_3d).
+  #[inline]
+  fn rotation_mat3(&mut self) -> Mat3 {
+    Mat3::from_cols_array_2d(&self.rotation_3d())
+  }
+}
+
+impl<G: Gen32 + ?Sized> GlamGeometry for G {}
@@ -0,0 +1,72 @@
+//! A thread-local generator, for when you don't want to pass one around.
+//!
+//! * Each thread gets its own [`Pcg32`], seeded the first time that thread uses
+//!   any function in this module.
+//! * The seed comes from the randomized keys of the standard library's
+//!   `RandomState`, so each run of the program gives different results.
+//! * Use [`reseed`] to get a reproducible sequence on the current thread, eg:
+//!   for tests.
+
+use super::*;
+use core::cell::RefCell;
+use std::{
+  collections::hash_map::RandomState,
+  hash::{BuildHasher, Hasher},
+};
+
+std::thread_local! {
+  static GLOBAL: RefCell<Pcg32> = RefCell::new(seed_from_std());
+}
+
+/// Gets a per-thread random seed by hashing with fresh `RandomState` keys.
+fn seed_from_std() -> Pcg32 {
+  let mut out = [0_u64; 2];
+  for (i, o) in out.iter_mut().enumerate() {
+    let mut h = RandomState::new().build_hasher();
+    h.write_usize(i);
+    *o = h.finish();
+  }
+  Pcg32::seed(out[0], out[1])
+}
+
+/// Runs a closure using the current thread's generator.
+///
+/// ## Panics
+/// * If called from within the closure of another `with` call.
+#[inline]
+pub fn with<R>(f: impl FnOnce(&mut Pcg32) -> R) -> R {
+  GLOBAL.with(|cell| f(&mut cell.borrow_mut()))
+}
+
+/// Re-seeds the current thread's generator, see [`Pcg32::seed`].
+#[inline]
+pub fn reseed(seed: u64, inc: u64) {
+  with(|g| *g = Pcg32::seed(seed, inc))
+}
+
+/// Gets a `u32` from the current thread's generator.
+#[inline]
+pub fn u32() -> u32 {
+  with(|g| g.next_u32())
+}
+
+/// Gets a value in `0 .. b` from the current thread's generator.
+///
+/// ## Panics
+/// * If `b` is 0, see [`Gen32::next_bounded`].
+#[inline]
+pub fn bounded(b: u32) -> u32 {
+  with(|g| g.next_bounded(b))
+}
+
+/// Gets an `f32` in `0.0 .. 1.0` from the current thread's generator.
+#[inline]
+pub fn f32_unit() -> f32 {
+  with(|g| g.next_f32_unit())
+}
+
+/// Shuffles a slice using the current thread's generator.
+#[inline]
+pub fn shuffle<T>(buf: &mut [T]) {
+  with(|g| g.shuffle(buf))
+}
@@ -0,0 +1,176 @@
+use super::*;
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::{__cpuid, __cpuid_count, _mm_pause, _rdrand32_step, _rdseed32_step};
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::{__cpuid, __cpuid_count, _mm_pause, _rdrand32_step, _rdseed32_step};
+
+/// How many times to retry `RDRAND` before giving up, as Intel recommends.
+const RDRAND_RETRIES: usize = 10;
+
+/// How many times to retry `RDSEED` before giving up. It fails more often
+/// than `RDRAND` under load, so we spin a while longer.
+const RDSEED_RETRIES: usize = 100;
+
+#[target_feature(enable = "rdrand")]
+unsafe fn rdrand32() -> Option<u32> {
+  for _ in 0..RDRAND_RETRIES {
+    let mut out = 0;
+    // Some AMD chips have a bug where after a suspend/resume cycle the
+    // instruction "succeeds" but always gives all 1s, so we treat that as a
+    // failure too.
+    if _rdrand32_step(&mut out) == 1 && out != u32::MAX {
+      return Some(out);
+    }
+  }
+  None
+}
+
+#[target_feature(enable = "rdseed")]
+unsafe fn rdseed32() -> Option<u32> {
+  for _ in 0..RDSEED_RETRIES {
+    let mut out = 0;
+    if _rdseed32_step(&mut out) == 1 && out != u32::MAX {
+      return Some(out);
+    }
+    _mm_pause();
+  }
+  None
+}
+
+/// Pulls seed values from the CPU's hardware random number generator, using
+/// the `RDSEED` and `RDRAND` instructions.
+///
+/// * Support for each instruction is checked (with `CPUID`) when you call
+///   [`new`](Self::new), and only supported instructions are ever used.
+/// * `RDSEED` gives values straight from the entropy source, so it's preferred
+///   for seeding. `RDRAND` gives values from a hardware CSPRNG that's reseeded
+///   from the same source, and is used as a fallback.
+/// * Either instruction can fail if the hardware is temporarily out of entropy.
+///   Each call retries a limited number of times and then returns `None`,
+///   instead of spinning forever.
+/// * Some AMD CPUs have a bug where the instructions always give all 1 bits
+///   after a suspend. An all 1s result is treated as a failure (and retried),
+///   so the value `u32::MAX` is never returned for any 32-bit chunk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HardwareSeed {
+  has_rdrand: bool,
+  has_rdseed: bool,
+}
+
+impl HardwareSeed {
+  /// Checks the CPU for hardware RNG support.
+  ///
+  /// ## Failure
+  /// * If the CPU supports neither `RDRAND` nor `RDSEED`.
+  #[inline]
+  pub fn new() -> Option<Self> {
+    #[allow(unused_unsafe)]
+    let (has_rdrand, has_rdseed) = unsafe {
+      let max_leaf = __cpuid(0).eax;
+      let has_rdrand = max_leaf >= 1 && (__cpuid(1).ecx & (1 << 30)) != 0;
+      let has_rdseed = max_leaf >= 7 && (__cpuid_count(7, 0).ebx & (1 << 18)) != 0;
+      (has_rdrand, has_rdseed)
+    };
+    if has_rdrand || has_rdseed {
+      Some(Self { has_rdrand, has_rdseed })
+    } else {
+      None
+    }
+  }
+
+  /// If the CPU supports `RDRAND`.
+  #[inline]
+  pub const fn has_rdrand(self) -> bool {
+    self.has_rdrand
+  }
+
+  /// If the CPU supports `RDSEED`.
+  #[inline]
+  pub const fn has_rdseed(self) -> bool {
+    self.has_rdseed
+  }
+
+  /// Gets a `u32` using `RDRAND`.
+  ///
+  /// ## Failure
+  /// * If `RDRAND` isn't supported, or fails too many times in a row.
+  #[inline]
+  pub fn rdrand_u32(self) -> Option<u32> {
+    if self.has_rdrand {
+      // Safety: we checked that the CPU supports the instruction.
+      unsafe { rdrand32() }
+    } else {
+      None
+    }
+  }
+
+  /// Gets a `u32` using `RDSEED`.
+  ///
+  /// ## Failure
+  /// * If `RDSEED` isn't supported, or fails too many times in a row.
+  #[inline]
+  pub fn rdseed_u32(self) -> Option<u32> {
+    if self.has_rdseed {
+      // Safety: we checked that the CPU supports the instruction.
+      unsafe { rdseed32() }
+    } else {
+      None
+    }
+  }
+
+  /// Gets a `u32` using `RDSEED`, falling back to `RDRAND`.
+  ///
+  /// ## Failure
+  /// * If neither instruction can give a value.
+  #[inline]
+  pub fn next_u32(self) -> Option<u32> {
+    self.rdseed_u32().or_else(|| self.rdrand_u32())
+  }
+
+  /// Gets a `u64` using `RDSEED`, falling back to `RDRAND`.
+  ///
+  /// ## Failure
+  /// * If neither instruction can give a value.
+  #[inline]
+  pub fn next_u64(self) -> Option<u64> {
+    let l = self.next_u32()? as u64;
+    let h = self.next_u32()? as u64;
+    Some(h << 32 | l)
+  }
+
+  /// Gets a `u128` using `RDSEED`, falling back to `RDRAND`.
+  ///
+  /// ## Failure
+  /// * If neither instruction can give a value.
+  #[inline]
+  pub fn next_u128(self) -> Option<u128> {
+    let l = self.next_u64()? as u128;
+    let h = self.next_u64()? as u128;
+    Some(h << 64 | l)
+  }
+}
+
+impl Pcg32 {
+  /// Seed a new generator using [`HardwareSeed`].
+  ///
+  /// ## Failure
+  /// * If the CPU has no hardware RNG, or it fails to give values.
+  #[inline]
+  pub fn seed_from_hardware() -> Option<Self> {
+    let hw = HardwareSeed::new()?;
+    Some(Self::seed(hw.next_u64()?, hw.next_u64()?))
+  }
+}
+
+impl Pcg64 {
+  /// Seed a new generator using [`HardwareSeed`].
+  ///
+  /// ## Failure
+  /// * If the CPU has no hardware RNG, or it fails to give values.
+  #[inline]
+  pub fn seed_from_hardware() -> Option<Self> {
+    let hw = HardwareSeed::new()?;
+    Some(Self::seed(hw.next_u128()?, hw.next_u128()?))
+  }
+}
@@ -0,0 +1,154 @@
+use super::*;
+
+const ISAAC_SIZE: usize = 256;
+const ISAAC_GOLDEN: u32 = 0x9e3779b9;
+
+/// The ISAAC seeding mix, over eight words.
+macro_rules! isaac_mix {
+  ($a:ident, $b:ident, $c:ident, $d:ident, $e:ident, $f:ident, $g:ident, $h:ident) => {
+    $a ^= $b << 11;
+    $d = $d.wrapping_add($a);
+    $b = $b.wrapping_add($c);
+    $b ^= $c >> 2;
+    $e = $e.wrapping_add($b);
+    $c = $c.wrapping_add($d);
+    $c ^= $d << 8;
+    $f = $f.wrapping_add($c);
+    $d = $d.wrapping_add($e);
+    $d ^= $e >> 16;
+    $g = $g.wrapping_add($d);
+    $e = $e.wrapping_add($f);
+    $e ^= $f << 10;
+    $h = $h.wrapping_add($e);
+    $f = $f.wrapping_add($g);
+    $f ^= $g >> 4;
+    $a = $a.wrapping_add($f);
+    $g = $g.wrapping_add($h);
+    $g ^= $h << 8;
+    $b = $b.wrapping_add($g);
+    $h = $h.wrapping_add($a);
+    $h ^= $a >> 9;
+    $c = $c.wrapping_add($h);
+    $a = $a.wrapping_add($b);
+  };
+}
+
+/// Bob Jenkins' [ISAAC](https://burtleburtle.net/bob/rand/isaacafa.html)
+/// generator, with 256 words of state and 32 bits of output per step.
+///
+/// * All of the math is done with `u32` values, but the design is much more
+///   conservative than the small generators, and no practical attack on it is
+///   known. It's still not recommended for cryptographic purposes.
+/// * Output is computed 256 words at a time and buffered, so most calls to
+///   `next_u32` are just a buffer read. Each buffer is read from the last word
+///   to the first, the same as the reference `rand` macro.
+/// * The state is a bit over 2k in size. Use the `serde` feature to save and
+///   restore the whole thing.
+/// * The methods on this type are quite minimal. You're expected to use the
+///   [`Gen32`] trait to provide most of the useful operations.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Isaac32 {
+  #[cfg_attr(feature = "serde", serde(rename = "mem_v1", with = "crate::serde_array"))]
+  mem: [u32; ISAAC_SIZE],
+  #[cfg_attr(feature = "serde", serde(rename = "rsl_v1", with = "crate::serde_array"))]
+  rsl: [u32; ISAAC_SIZE],
+  #[cfg_attr(feature = "serde", serde(rename = "a_v1"))]
+  a: u32,
+  #[cfg_attr(feature = "serde", serde(rename = "b_v1"))]
+  b: u32,
+  #[cfg_attr(feature = "serde", serde(rename = "c_v1"))]
+  c: u32,
+  /// Number of unread words left in `rsl`.
+  #[cfg_attr(feature = "serde", serde(rename = "count_v1"))]
+  count: usize,
+}
+
+impl Isaac32 {
+  /// Seed a new generator (the reference `randinit`).
+  ///
+  /// Up to 256 words of seed are used, and a shorter seed is padded with
+  /// zeros.
+  pub fn seed_from_slice(seed: &[u32]) -> Self {
+    let mut out = Self { mem: [0; ISAAC_SIZE], rsl: [0; ISAAC_SIZE], a: 0, b: 0, c: 0, count: 0 };
+    for (r, s) in out.rsl.iter_mut().zip(seed.iter()) {
+      *r = *s;
+    }
+    let (mut a, mut b, mut c, mut d) = (ISAAC_GOLDEN, ISAAC_GOLDEN, ISAAC_GOLDEN, ISAAC_GOLDEN);
+    let (mut e, mut f, mut g, mut h) = (ISAAC_GOLDEN, ISAAC_GOLDEN, ISAAC_GOLDEN, ISAAC_GOLDEN);
+    for _ in 0..4 {
+      isaac_mix!(a, b, c, d, e, f, g, h);
+    }
+    for pass in 0..2 {
+      for i in (0..ISAAC_SIZE).step_by(8) {
+        let src = if pass == 0 { &out.rsl } else { &out.mem };
+        a = a.wrapping_add(src[i]);
+        b = b.wrapping_add(src[i + 1]);
+        c = c.wrapping_add(src[i + 2]);
+        d = d.wrapping_add(src[i + 3]);
+        e = e.wrapping_add(src[i + 4]);
+        f = f.wrapping_add(src[i + 5]);
+        g = g.wrapping_add(src[i + 6]);
+        h = h.wrapping_add(src[i + 7]);
+        isaac_mix!(a, b, c, d, e, f, g, h);
+        out.mem[i..i + 8].copy_from_slice(&[a, b, c, d, e, f, g, h]);
+      }
+    }
+    out.isaac();
+    out
+  }
+
+  /// Fills `rsl` with the next 256 outputs.
+  fn isaac(&mut self) {
+    self.c = self.c.wrapping_add(1);
+    self.b = self.b.wrapping_add(self.c);
+    let mem = &mut self.mem;
+    let mut a = self.a;
+    let mut b = self.b;
+    for i in 0..ISAAC_SIZE {
+      let x = mem[i];
+      a = match i % 4 {
+        0 => a ^ (a << 13),
+        1 => a ^ (a >> 6),
+        2 => a ^ (a << 2),
+        _ => a ^ (a >> 16),
+      };
+      a = mem[(i + ISAAC_SIZE / 2) % ISAAC_SIZE].wrapping_add(a);
+      let y = mem[((x >> 2) as usize) % ISAAC_SIZE].wrapping_add(a).wrapping_add(b);
+      mem[i] = y;
+      b = mem[((y >> 10) as usize) % ISAAC_SIZE].wrapping_add(x);
+      self.rsl[i] = b;
+    }
+    self.a = a;
+    self.b = b;
+    self.count = ISAAC_SIZE;
+  }
+
+  /// Gets the next 32-bits of output.
+  #[inline]
+  pub fn next_u32(&mut self) -> u32 {
+    // An out of range count (from a bad deserialize) is treated as empty.
+    if self.count == 0 || self.count > ISAAC_SIZE {
+      self.isaac();
+    }
+    self.count -= 1;
+    self.rsl[self.count]
+  }
+}
+
+impl Default for Isaac32 {
+  fn default() -> Self {
+    Self::seed_from_slice(&[
+      DEFAULT_PCG_SEED as u32,
+      (DEFAULT_PCG_SEED >> 32) as u32,
+      (DEFAULT_PCG_SEED >> 64) as u32,
+      (DEFAULT_PCG_SEED >> 96) as u32,
+    ])
+  }
+}
+
+impl Gen32 for Isaac32 {
+  fn next_u32(&mut self) -> u32 {
+    Isaac32::next_u32(self)
+  }
+}
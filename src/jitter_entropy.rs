@@ -0,0 +1,83 @@
+use super::*;
+
+/// Gathers entropy from noisy samples, to seed a generator with.
+///
+/// This is for targets with no OS and no hardware RNG, such as
+/// microcontrollers. Feed it anything that varies unpredictably: the low bits
+/// of an ADC reading on a floating pin, a timer sampled from an interrupt, the
+/// cycle count of a loop measured against a second clock, and so on.
+///
+/// * Internally this is a small sponge: each sample is absorbed into one word
+///   of a 256-bit state, and the whole state is then stirred with the
+///   [`SplitMix64`] mixing function.
+/// * Individual samples can be low quality. Only their combined
+///   unpredictability matters, so feed in many more samples than the seed size.
+///   As a rule of thumb, if each sample has about 1 bit of real noise, feed at
+///   least 256.
+/// * [`finalize`](Self::finalize) gives a seed suitable for [`Pcg32::seed`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct JitterEntropy {
+  state: [u64; 4],
+  samples: u64,
+}
+
+impl JitterEntropy {
+  /// Makes a new, empty collector.
+  pub const fn new() -> Self {
+    Self {
+      state: [
+        DEFAULT_PCG_SEED as u64,
+        (DEFAULT_PCG_SEED >> 64) as u64,
+        DEFAULT_PCG_INC as u64,
+        (DEFAULT_PCG_INC >> 64) as u64,
+      ],
+      samples: 0,
+    }
+  }
+
+  /// Absorbs a sample.
+  #[inline]
+  pub fn feed(&mut self, sample: u32) {
+    let lane = (self.samples % 4) as usize;
+    self.state[lane] ^= (sample as u64) | (self.samples << 32);
+    self.samples = self.samples.wrapping_add(1);
+    self.stir();
+  }
+
+  /// The number of samples fed in so far.
+  #[inline]
+  pub const fn samples(&self) -> u64 {
+    self.samples
+  }
+
+  /// Gets a seed from the collector, for use with [`Pcg32::seed`].
+  ///
+  /// This doesn't change the collector, so you can keep feeding it and
+  /// finalize again later for another seed.
+  #[inline]
+  pub fn finalize(&self) -> (u64, u64) {
+    let mut sponge = self.clone();
+    sponge.state[0] ^= sponge.samples;
+    sponge.stir();
+    sponge.stir();
+    (sponge.state[0], sponge.state[1])
+  }
+
+  /// Mixes every state word with the one before it, so each sample spreads
+  /// into the full state.
+  #[inline]
+  fn stir(&mut self) {
+    let [a, b, c, d] = self.state;
+    let a = splitmix64_mix(a.wrapping_add(d.rotate_left(17)));
+    let b = splitmix64_mix(b.wrapping_add(a));
+    let c = splitmix64_mix(c.wrapping_add(b));
+    let d = splitmix64_mix(d.wrapping_add(c));
+    self.state = [a, b, c, d];
+  }
+}
+
+impl Default for JitterEntropy {
+  fn default() -> Self {
+    Self::new()
+  }
+}
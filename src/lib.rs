@@ -6,6 +6,26 @@
 //! Pseudo-randomized number generator crate.
 //!
 //! NOT FOR CRYPTOGRAPHIC PURPOSES.
+//!
+//! ## Cargo Features
+//! * `rand_core`: Implements `RngCore` and `SeedableRng` for the generators in
+//!   this crate, and provides `RandCoreGen` to use any `RngCore` as a
+//!   [`Gen32`].
+//! * `getrandom`: Adds a `from_entropy` constructor to every generator, which
+//!   seeds it from the operating system's entropy source.
+//! * `std`: Adds the [`global`] module, with a thread-local generator and free
+//!   functions to use it, and the `Vec` based [`LootTable`] and [`DrawPile`].
+//! * `gba`: Adds [`EntropyPool`], which gathers a seed from timers and key
+//!   input on the GBA.
+//! * `serde`: Implements `Serialize` and `Deserialize` for the generators in
+//!   this crate. Field names carry a version suffix (eg: `state_v1`), so a
+//!   saved state from an older crate version will either load exactly or fail
+//!   to load, never silently load as a different sequence.
+//! * `glam`: Adds [`GlamGeometry`], with versions of the [`Geometry`] methods
+//!   that use `glam` vector, quaternion, and matrix types.
+
+#[cfg(feature = "std")]
+extern crate std;
 
 /// A default seed for any PCG.
 ///
@@ -45,11 +65,160 @@ macro_rules! make_jump_lcgX {
 mod gen32;
 pub use gen32::*;
 
+mod gen64;
+pub use gen64::*;
+
 mod pcg32;
 pub use pcg32::*;
 
-// mod bounded_rand;
-// pub use bounded_rand::*;
+mod pcg64;
+pub use pcg64::*;
+
+mod bounded_rand;
+pub use bounded_rand::*;
+
+mod dice;
+pub use dice::*;
+
+mod dice_expr;
+pub use dice_expr::*;
+
+mod pseudo_random_chance;
+pub use pseudo_random_chance::*;
+
+mod pity_table;
+pub use pity_table::*;
+
+mod loot_table;
+pub use loot_table::*;
+
+mod shuffle_bag;
+pub use shuffle_bag::*;
+
+mod draw_pile;
+pub use draw_pile::*;
+
+mod encounter_clock;
+pub use encounter_clock::*;
+
+mod poisson_process;
+pub use poisson_process::*;
+
+mod random_walk;
+pub use random_walk::*;
+
+mod markov_chain;
+pub use markov_chain::*;
+
+mod geometry;
+pub use geometry::*;
+
+mod low_discrepancy;
+pub use low_discrepancy::*;
+
+mod poisson_disk;
+pub use poisson_disk::*;
+
+mod distributions;
+pub use distributions::*;
+
+mod noise;
+pub use noise::*;
+
+mod pink_noise;
+pub use pink_noise::*;
+
+mod noise_stream;
+pub use noise_stream::*;
+
+mod dither;
+pub use dither::*;
+
+mod chacha;
+pub use chacha::*;
+
+mod isaac32;
+pub use isaac32::*;
+
+mod jitter_entropy;
+pub use jitter_entropy::*;
+
+mod jsf32;
+pub use jsf32::*;
+
+mod lehmer128;
+pub use lehmer128::*;
+
+mod msws;
+pub use msws::*;
+
+mod mt19937;
+pub use mt19937::*;
+
+mod mwc;
+pub use mwc::*;
+
+mod philox;
+pub use philox::*;
 
 mod rng;
-pub use rng::*;
\ No newline at end of file
+pub use rng::*;
+
+mod romu;
+pub use romu::*;
+
+mod sfc32;
+pub use sfc32::*;
+
+mod splitmix64;
+pub use splitmix64::*;
+
+mod threefry;
+pub use threefry::*;
+
+mod tinymt32;
+pub use tinymt32::*;
+
+mod well512;
+pub use well512::*;
+
+mod wyrand;
+pub use wyrand::*;
+
+mod xorshift128;
+pub use xorshift128::*;
+
+mod xoshiro128pp;
+pub use xoshiro128pp::*;
+
+mod xoshiro256pp;
+pub use xoshiro256pp::*;
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod hardware_seed;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use hardware_seed::*;
+
+#[cfg(feature = "getrandom")]
+mod entropy;
+
+#[cfg(feature = "std")]
+pub mod global;
+
+#[cfg(feature = "gba")]
+mod gba_entropy;
+#[cfg(feature = "gba")]
+pub use gba_entropy::*;
+
+#[cfg(feature = "serde")]
+mod serde_array;
+
+#[cfg(feature = "rand_core")]
+mod rand_core_compat;
+#[cfg(feature = "rand_core")]
+pub use rand_core_compat::*;
+
+#[cfg(feature = "glam")]
+mod glam_compat;
+#[cfg(feature = "glam")]
+pub use glam_compat::*;
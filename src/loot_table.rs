@@ -0,0 +1,272 @@
+use super::*;
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+/// One item in a loot table.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct LootEntry<T> {
+  /// The index of the tier that this entry is in.
+  pub tier: usize,
+  /// The entry's weight within its tier. An entry with 0 weight never drops.
+  pub weight: u32,
+  /// The item itself.
+  pub item: T,
+}
+
+impl<T> LootEntry<T> {
+  /// Makes a new entry.
+  #[inline]
+  pub const fn new(tier: usize, weight: u32, item: T) -> Self {
+    Self { tier, weight, item }
+  }
+}
+
+/// A loot table with rarity tiers, stored in arrays.
+///
+/// * Each draw first picks a tier (by the tier weights), then picks an entry
+///   within that tier (by the entry weights). This lets you tune "how often is
+///   something rare" separately from "which rare thing is it".
+/// * Tiers that have no entries left to draw (all 0 weight, or all already
+///   drawn) are skipped, and the other tiers are picked in proportion to their
+///   weights as usual.
+/// * This needs no allocation. With the `std` feature there's also
+///   [`LootTable`], which can be built up one entry at a time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ArrayLootTable<T, const TIERS: usize, const N: usize> {
+  tiers: [u32; TIERS],
+  entries: [LootEntry<T>; N],
+}
+
+impl<T, const TIERS: usize, const N: usize> ArrayLootTable<T, TIERS, N> {
+  /// Constructs a new table.
+  ///
+  /// ## Panics
+  /// * See [`try_new`](Self::try_new).
+  #[inline]
+  pub fn new(tiers: [u32; TIERS], entries: [LootEntry<T>; N]) -> Self {
+    match Self::try_new(tiers, entries) {
+      Some(table) => table,
+      None => panic!("ArrayLootTable::new> An entry has an invalid tier."),
+    }
+  }
+
+  /// Constructs a new table, or `None` on failure.
+  ///
+  /// ## Failure
+  /// * If any entry's tier isn't an index into `tiers`.
+  #[inline]
+  pub fn try_new(tiers: [u32; TIERS], entries: [LootEntry<T>; N]) -> Option<Self> {
+    if entries.iter().all(|e| e.tier < TIERS) {
+      Some(Self { tiers, entries })
+    } else {
+      None
+    }
+  }
+
+  /// The tier weights.
+  #[inline]
+  pub fn tiers(&self) -> &[u32; TIERS] {
+    &self.tiers
+  }
+
+  /// The entries.
+  #[inline]
+  pub fn entries(&self) -> &[LootEntry<T>; N] {
+    &self.entries
+  }
+
+  /// Draws the index of an entry.
+  ///
+  /// ## Failure
+  /// * If no tier with weight has any entry with weight.
+  #[inline]
+  pub fn draw_index<G: Gen32 + ?Sized>(&self, gen: &mut G) -> Option<usize> {
+    draw_loot_index(&self.tiers, &self.entries, |_| true, gen)
+  }
+
+  /// Draws an item.
+  ///
+  /// ## Failure
+  /// * If no tier with weight has any entry with weight.
+  #[inline]
+  pub fn draw<G: Gen32 + ?Sized>(&self, gen: &mut G) -> Option<&T> {
+    self.draw_index(gen).map(|i| &self.entries[i].item)
+  }
+
+  /// Draws entry indexes into `out` with no entry drawn twice, giving how
+  /// many were drawn.
+  ///
+  /// * Each draw is made as if the entries already drawn had been removed from
+  ///   the table.
+  /// * Fewer than `out.len()` are drawn if the table runs out of entries that
+  ///   can drop.
+  #[inline]
+  pub fn draw_distinct<G: Gen32 + ?Sized>(&self, gen: &mut G, out: &mut [usize]) -> usize {
+    let mut taken = [false; N];
+    draw_distinct_loot_indexes(&self.tiers, &self.entries, &mut taken, gen, out)
+  }
+}
+
+/// A loot table with rarity tiers, which can be built up at runtime.
+///
+/// * This works like [`ArrayLootTable`], but keeps the tiers and entries in
+///   `Vec`s, so it's only available with the `std` feature.
+/// * Tiers are numbered from 0 in the order that they're added.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct LootTable<T> {
+  tiers: Vec<u32>,
+  entries: Vec<LootEntry<T>>,
+}
+
+#[cfg(feature = "std")]
+impl<T> LootTable<T> {
+  /// Makes an empty table.
+  #[inline]
+  pub const fn new() -> Self {
+    Self { tiers: Vec::new(), entries: Vec::new() }
+  }
+
+  /// Adds a tier with the given weight, giving its index.
+  #[inline]
+  pub fn add_tier(&mut self, weight: u32) -> usize {
+    self.tiers.push(weight);
+    self.tiers.len() - 1
+  }
+
+  /// Adds an entry to a tier.
+  ///
+  /// ## Panics
+  /// * If the tier hasn't been added.
+  #[inline]
+  pub fn add_entry(&mut self, tier: usize, weight: u32, item: T) {
+    assert!(tier < self.tiers.len(), "LootTable::add_entry> The tier hasn't been added.");
+    self.entries.push(LootEntry { tier, weight, item });
+  }
+
+  /// Adds a tier, builder style.
+  #[inline]
+  pub fn with_tier(mut self, weight: u32) -> Self {
+    self.add_tier(weight);
+    self
+  }
+
+  /// Adds an entry, builder style.
+  ///
+  /// ## Panics
+  /// * If the tier hasn't been added.
+  #[inline]
+  pub fn with_entry(mut self, tier: usize, weight: u32, item: T) -> Self {
+    self.add_entry(tier, weight, item);
+    self
+  }
+
+  /// The tier weights.
+  #[inline]
+  pub fn tiers(&self) -> &[u32] {
+    &self.tiers
+  }
+
+  /// The entries.
+  #[inline]
+  pub fn entries(&self) -> &[LootEntry<T>] {
+    &self.entries
+  }
+
+  /// Draws the index of an entry.
+  ///
+  /// ## Failure
+  /// * If no tier with weight has any entry with weight.
+  #[inline]
+  pub fn draw_index<G: Gen32 + ?Sized>(&self, gen: &mut G) -> Option<usize> {
+    draw_loot_index(&self.tiers, &self.entries, |_| true, gen)
+  }
+
+  /// Draws an item.
+  ///
+  /// ## Failure
+  /// * If no tier with weight has any entry with weight.
+  #[inline]
+  pub fn draw<G: Gen32 + ?Sized>(&self, gen: &mut G) -> Option<&T> {
+    self.draw_index(gen).map(|i| &self.entries[i].item)
+  }
+
+  /// Draws up to `count` items with no entry drawn twice.
+  ///
+  /// * Each draw is made as if the entries already drawn had been removed from
+  ///   the table.
+  /// * Fewer than `count` are drawn if the table runs out of entries that can
+  ///   drop.
+  #[inline]
+  pub fn draw_distinct<G: Gen32 + ?Sized>(&self, gen: &mut G, count: usize) -> Vec<&T> {
+    let mut taken = std::vec![false; self.entries.len()];
+    let mut indexes = std::vec![0; count.min(self.entries.len())];
+    let n = draw_distinct_loot_indexes(&self.tiers, &self.entries, &mut taken, gen, &mut indexes);
+    indexes[..n].iter().map(|&i| &self.entries[i].item).collect()
+  }
+}
+
+/// Draws an entry index, considering only the entries where `available` is
+/// true.
+fn draw_loot_index<T, G: Gen32 + ?Sized>(
+  tiers: &[u32], entries: &[LootEntry<T>], available: impl Fn(usize) -> bool, gen: &mut G,
+) -> Option<usize> {
+  let tier_total = |tier: usize| -> u64 {
+    entries
+      .iter()
+      .enumerate()
+      .filter(|(i, e)| e.tier == tier && available(*i))
+      .map(|(_, e)| e.weight as u64)
+      .sum()
+  };
+  let live_weight = |tier: usize| -> u64 {
+    if tiers[tier] != 0 && tier_total(tier) != 0 {
+      tiers[tier] as u64
+    } else {
+      0
+    }
+  };
+  let total: u64 = (0..tiers.len()).map(live_weight).sum();
+  if total == 0 {
+    return None;
+  }
+  let mut r = next_bounded_u64(gen, total);
+  let mut tier = 0;
+  for t in 0..tiers.len() {
+    let w = live_weight(t);
+    if r < w {
+      tier = t;
+      break;
+    }
+    r -= w;
+  }
+  let mut r = next_bounded_u64(gen, tier_total(tier));
+  for (i, e) in entries.iter().enumerate() {
+    if e.tier != tier || !available(i) {
+      continue;
+    }
+    let w = e.weight as u64;
+    if r < w {
+      return Some(i);
+    }
+    r -= w;
+  }
+  unreachable!()
+}
+
+/// Draws distinct entry indexes into `out`, marking them in `taken`, and gives
+/// how many were drawn.
+fn draw_distinct_loot_indexes<T, G: Gen32 + ?Sized>(
+  tiers: &[u32], entries: &[LootEntry<T>], taken: &mut [bool], gen: &mut G, out: &mut [usize],
+) -> usize {
+  for (n, o) in out.iter_mut().enumerate() {
+    match draw_loot_index(tiers, entries, |i| !taken[i], gen) {
+      Some(i) => {
+        taken[i] = true;
+        *o = i;
+      }
+      None => return n,
+    }
+  }
+  out.len()
+}
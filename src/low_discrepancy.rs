@@ -0,0 +1,401 @@
+use super::*;
+
+/// The most dimensions that [`Sobol`] and [`Halton`] support.
+pub const LOW_DISCREPANCY_MAX_DIMS: usize = 16;
+
+/// Primitive polynomials and initial direction numbers for Sobol dimensions 2
+/// and up, as `(degree, coefficients, m)`, from Joe and Kuo's
+/// `new-joe-kuo-6.21201` table.
+#[rustfmt::skip]
+const SOBOL_PARAMS: [(u32, u32, [u32; 6]); LOW_DISCREPANCY_MAX_DIMS - 1] = [
+  (1, 0, [1, 0, 0, 0, 0, 0]),
+  (2, 1, [1, 3, 0, 0, 0, 0]),
+  (3, 1, [1, 3, 1, 0, 0, 0]),
+  (3, 2, [1, 1, 1, 0, 0, 0]),
+  (4, 1, [1, 1, 3, 3, 0, 0]),
+  (4, 4, [1, 3, 5, 13, 0, 0]),
+  (5, 2, [1, 1, 5, 5, 17, 0]),
+  (5, 4, [1, 1, 5, 5, 5, 0]),
+  (5, 7, [1, 1, 7, 11, 19, 0]),
+  (5, 11, [1, 1, 5, 1, 1, 0]),
+  (5, 13, [1, 1, 1, 3, 11, 0]),
+  (5, 14, [1, 3, 5, 5, 31, 0]),
+  (6, 1, [1, 3, 3, 9, 7, 49]),
+  (6, 13, [1, 1, 1, 15, 21, 21]),
+  (6, 16, [1, 3, 1, 13, 27, 49]),
+];
+
+/// The first [`LOW_DISCREPANCY_MAX_DIMS`] primes, the bases for [`Halton`].
+const HALTON_BASES: [u32; LOW_DISCREPANCY_MAX_DIMS] =
+  [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53];
+
+/// The Sobol low-discrepancy sequence, in `D` dimensions.
+///
+/// * Each output is a point in the unit hypercube. Unlike random points, the
+///   points spread out evenly: the first `2^k` points put exactly one point in
+///   each of `2^k` equal slices of every axis. This makes estimates
+///   (quasi-Monte-Carlo) converge much faster than with random points.
+/// * `D` can be from 1 to [`LOW_DISCREPANCY_MAX_DIMS`].
+/// * The plain sequence always starts at the origin and is always the same. Use
+///   [`scramble`](Self::scramble) to get a randomized version that keeps the
+///   even spacing.
+/// * The sequence has `2^32` points, and then starts over.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Sobol<const D: usize> {
+  directions: [[u32; 32]; D],
+  shift: [u32; D],
+  point: [u32; D],
+  index: u32,
+}
+
+impl<const D: usize> Sobol<D> {
+  /// Makes a new sequence.
+  ///
+  /// ## Panics
+  /// * If `D` is 0 or more than [`LOW_DISCREPANCY_MAX_DIMS`].
+  #[inline]
+  pub fn new() -> Self {
+    assert!(
+      D > 0 && D <= LOW_DISCREPANCY_MAX_DIMS,
+      "Sobol::new> Unsupported number of dimensions."
+    );
+    let mut directions = [[0_u32; 32]; D];
+    for (d, v) in directions.iter_mut().enumerate() {
+      if d == 0 {
+        // The first dimension is the van der Corput sequence in base 2.
+        for (i, v) in v.iter_mut().enumerate() {
+          *v = 1 << (31 - i);
+        }
+        continue;
+      }
+      let (s, a, m) = SOBOL_PARAMS[d - 1];
+      let s = s as usize;
+      for i in 0..32 {
+        v[i] = if i < s {
+          m[i] << (31 - i)
+        } else {
+          let mut x = v[i - s] ^ (v[i - s] >> s);
+          for k in 1..s {
+            if (a >> (s - 1 - k)) & 1 != 0 {
+              x ^= v[i - k];
+            }
+          }
+          x
+        };
+      }
+    }
+    Self { directions, shift: [0; D], point: [0; D], index: 0 }
+  }
+
+  /// Randomizes the sequence with a random digital shift.
+  ///
+  /// * Every point is XORed with the same random value (one per dimension),
+  ///   which keeps the even spacing but moves the points around, so each
+  ///   scrambled sequence gives different (but still low discrepancy) points.
+  #[inline]
+  pub fn scramble<G: Gen32 + ?Sized>(mut self, gen: &mut G) -> Self {
+    self.shift.iter_mut().for_each(|s| *s = gen.next_u32());
+    self
+  }
+
+  /// The number of points given so far (wrapping).
+  #[inline]
+  pub const fn index(&self) -> u32 {
+    self.index
+  }
+
+  /// Gives the next point, with each value as a `u32` (as a fraction of
+  /// `2^32`).
+  #[inline]
+  pub fn next_u32s(&mut self) -> [u32; D] {
+    let mut out = self.point;
+    for (o, s) in out.iter_mut().zip(self.shift.iter()) {
+      *o ^= s;
+    }
+    // Gray code order: each step flips the direction for the lowest 0 bit.
+    let c = (!self.index).trailing_zeros() as usize & 31;
+    for (p, v) in self.point.iter_mut().zip(self.directions.iter()) {
+      *p ^= v[c];
+    }
+    self.index = self.index.wrapping_add(1);
+    out
+  }
+
+  /// Gives the next point, with each value in `0.0 .. 1.0`.
+  #[inline]
+  pub fn next_f32_unit(&mut self) -> [f32; D] {
+    let mut out = [0.0; D];
+    for (o, x) in out.iter_mut().zip(self.next_u32s().iter()) {
+      *o = (x >> 8) as f32 * (1.0 / (1_u32 << 24) as f32);
+    }
+    out
+  }
+
+  /// Gives the next point, with each value in `0.0 .. 1.0`.
+  ///
+  /// The values have only 32 bits of precision.
+  #[inline]
+  pub fn next_f64_unit(&mut self) -> [f64; D] {
+    let mut out = [0.0; D];
+    for (o, x) in out.iter_mut().zip(self.next_u32s().iter()) {
+      *o = *x as f64 * (1.0 / (1_u64 << 32) as f64);
+    }
+    out
+  }
+}
+
+impl<const D: usize> Default for Sobol<D> {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+/// The Halton low-discrepancy sequence, in `D` dimensions.
+///
+/// * Each output is a point in the unit hypercube, spread out evenly like
+///   [`Sobol`]. Each axis is the digits of the point's index, reversed behind
+///   the "decimal" point, in a different prime base.
+/// * `D` can be from 1 to [`LOW_DISCREPANCY_MAX_DIMS`]. Higher dimensions use
+///   larger bases, and their pairs start to show visible stripes, so Halton is
+///   best for just a few dimensions.
+/// * The plain sequence always starts at the origin and is always the same. Use
+///   [`scramble`](Self::scramble) to get a randomized version.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Halton<const D: usize> {
+  shift: [f64; D],
+  index: u32,
+}
+
+impl<const D: usize> Halton<D> {
+  /// Makes a new sequence.
+  ///
+  /// ## Panics
+  /// * If `D` is 0 or more than [`LOW_DISCREPANCY_MAX_DIMS`].
+  #[inline]
+  pub fn new() -> Self {
+    assert!(
+      D > 0 && D <= LOW_DISCREPANCY_MAX_DIMS,
+      "Halton::new> Unsupported number of dimensions."
+    );
+    Self { shift: [0.0; D], index: 0 }
+  }
+
+  /// Randomizes the sequence with a random rotation.
+  ///
+  /// * Every point has the same random offset added (one per dimension), and
+  ///   then wraps around into `0.0 .. 1.0`. This keeps the even spacing but
+  ///   moves the points around.
+  #[inline]
+  pub fn scramble<G: Gen32 + ?Sized>(mut self, gen: &mut G) -> Self {
+    self.shift.iter_mut().for_each(|s| *s = gen.next_f64_unit());
+    self
+  }
+
+  /// The number of points given so far (wrapping).
+  #[inline]
+  pub const fn index(&self) -> u32 {
+    self.index
+  }
+
+  /// Gives the next point, with each value in `0.0 .. 1.0`.
+  #[inline]
+  pub fn next_f64_unit(&mut self) -> [f64; D] {
+    let mut out = [0.0; D];
+    for ((o, &base), shift) in out.iter_mut().zip(HALTON_BASES.iter()).zip(self.shift.iter()) {
+      let mut x = radical_inverse(self.index, base) + shift;
+      if x >= 1.0 {
+        x -= 1.0;
+      }
+      *o = x;
+    }
+    self.index = self.index.wrapping_add(1);
+    out
+  }
+
+  /// Gives the next point, with each value in `0.0 .. 1.0`.
+  #[inline]
+  pub fn next_f32_unit(&mut self) -> [f32; D] {
+    let mut out = [0.0; D];
+    for (o, x) in out.iter_mut().zip(self.next_f64_unit().iter()) {
+      // Rounding to `f32` could give 1.0, so keep it in range.
+      *o = (*x as f32).min(1.0 - f32::EPSILON / 2.0);
+    }
+    out
+  }
+}
+
+impl<const D: usize> Default for Halton<D> {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+/// Reverses the digits of `i` in `base` to behind the point.
+#[inline]
+fn radical_inverse(mut i: u32, base: u32) -> f64 {
+  let inv_base = 1.0 / base as f64;
+  let (mut out, mut scale) = (0.0, inv_base);
+  while i > 0 {
+    out += (i % base) as f64 * scale;
+    i /= base;
+    scale *= inv_base;
+  }
+  out
+}
+
+/// `2^32` divided by the golden ratio.
+const R1_ALPHA: u32 = 0x9E37_79B9;
+
+/// `2^32` divided by the plastic constant, and by its square.
+const R2_ALPHA: [u32; 2] = [0xC13F_A9A9, 0x91E1_0DA6];
+
+/// The R1 (golden ratio) low-discrepancy sequence, in 1 dimension.
+///
+/// * Each step adds a fixed fraction (one over the golden ratio) and wraps
+///   around, so this is just one add per point. The points fill in `0.0 .. 1.0`
+///   evenly no matter how many you take.
+/// * The sequence starts at 0.5, as recommended. Use
+///   [`from_offset`](Self::from_offset) or [`scramble`](Self::scramble) to
+///   start somewhere else.
+/// * The sequence has `2^32` points, and then starts over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct R1 {
+  #[cfg_attr(feature = "serde", serde(rename = "state_v1"))]
+  state: u32,
+}
+
+impl R1 {
+  /// Makes a new sequence, starting at 0.5.
+  #[inline]
+  pub const fn new() -> Self {
+    Self::from_offset(1 << 31)
+  }
+
+  /// Makes a new sequence, starting at `offset / 2^32`.
+  #[inline]
+  pub const fn from_offset(offset: u32) -> Self {
+    Self { state: offset }
+  }
+
+  /// Moves the sequence to start at a random position.
+  #[inline]
+  pub fn scramble<G: Gen32 + ?Sized>(self, gen: &mut G) -> Self {
+    Self::from_offset(gen.next_u32())
+  }
+
+  /// Skips ahead `n` points (or back, using a "negative" `n`).
+  #[inline]
+  pub fn jump(&mut self, n: u32) {
+    self.state = self.state.wrapping_add(R1_ALPHA.wrapping_mul(n));
+  }
+
+  /// Gives the next point, as a `u32` (as a fraction of `2^32`).
+  #[inline]
+  pub fn next_u32(&mut self) -> u32 {
+    let out = self.state;
+    self.state = self.state.wrapping_add(R1_ALPHA);
+    out
+  }
+
+  /// Gives the next point, in `0.0 .. 1.0`.
+  #[inline]
+  pub fn next_f32_unit(&mut self) -> f32 {
+    (self.next_u32() >> 8) as f32 * (1.0 / (1_u32 << 24) as f32)
+  }
+
+  /// Gives the next point, in `0.0 .. 1.0`.
+  ///
+  /// The value has only 32 bits of precision.
+  #[inline]
+  pub fn next_f64_unit(&mut self) -> f64 {
+    self.next_u32() as f64 * (1.0 / (1_u64 << 32) as f64)
+  }
+}
+
+impl Default for R1 {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+/// The R2 (plastic constant) low-discrepancy sequence, in 2 dimensions.
+///
+/// * Each step adds a fixed fraction per axis (one over the plastic constant,
+///   and over its square) and wraps around, so this is just two adds per point.
+///   The points fill the unit square evenly no matter how many you take, which
+///   makes this good for placing things that shouldn't clump.
+/// * The sequence starts at `(0.5, 0.5)`, as recommended. Use
+///   [`from_offset`](Self::from_offset) or [`scramble`](Self::scramble) to
+///   start somewhere else.
+/// * The sequence has `2^32` points, and then starts over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct R2 {
+  #[cfg_attr(feature = "serde", serde(rename = "state_v1"))]
+  state: [u32; 2],
+}
+
+impl R2 {
+  /// Makes a new sequence, starting at `(0.5, 0.5)`.
+  #[inline]
+  pub const fn new() -> Self {
+    Self::from_offset([1 << 31, 1 << 31])
+  }
+
+  /// Makes a new sequence, starting at `offset / 2^32` on each axis.
+  #[inline]
+  pub const fn from_offset(offset: [u32; 2]) -> Self {
+    Self { state: offset }
+  }
+
+  /// Moves the sequence to start at a random position.
+  #[inline]
+  pub fn scramble<G: Gen32 + ?Sized>(self, gen: &mut G) -> Self {
+    Self::from_offset([gen.next_u32(), gen.next_u32()])
+  }
+
+  /// Skips ahead `n` points (or back, using a "negative" `n`).
+  #[inline]
+  pub fn jump(&mut self, n: u32) {
+    self.state[0] = self.state[0].wrapping_add(R2_ALPHA[0].wrapping_mul(n));
+    self.state[1] = self.state[1].wrapping_add(R2_ALPHA[1].wrapping_mul(n));
+  }
+
+  /// Gives the next point, with each value as a `u32` (as a fraction of
+  /// `2^32`).
+  #[inline]
+  pub fn next_u32s(&mut self) -> [u32; 2] {
+    let out = self.state;
+    self.jump(1);
+    out
+  }
+
+  /// Gives the next point, with each value in `0.0 .. 1.0`.
+  #[inline]
+  pub fn next_f32_unit(&mut self) -> [f32; 2] {
+    let [x, y] = self.next_u32s();
+    let scale = 1.0 / (1_u32 << 24) as f32;
+    [(x >> 8) as f32 * scale, (y >> 8) as f32 * scale]
+  }
+
+  /// Gives the next point, with each value in `0.0 .. 1.0`.
+  ///
+  /// The values have only 32 bits of precision.
+  #[inline]
+  pub fn next_f64_unit(&mut self) -> [f64; 2] {
+    let [x, y] = self.next_u32s();
+    let scale = 1.0 / (1_u64 << 32) as f64;
+    [x as f64 * scale, y as f64 * scale]
+  }
+}
+
+impl Default for R2 {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
@@ -0,0 +1,231 @@
+use super::*;
+
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+/// A square matrix of transition weights for a [`MarkovChain`].
+///
+/// * Row `i` holds the weights of moving from state `i` to each state.
+/// * This is implemented for arrays, slices, and (with `std`) `Vec`s of rows,
+///   where each row is anything that's `AsRef<[u32]>`. A single flat buffer can
+///   be used with [`FlatWeights`].
+pub trait TransitionWeights {
+  /// The number of states (the number of rows).
+  fn state_count(&self) -> usize;
+
+  /// The weights of moving from `state` to each state.
+  ///
+  /// ## Panics
+  /// * If `state` isn't less than the state count.
+  fn row(&self, state: usize) -> &[u32];
+}
+
+impl<R: AsRef<[u32]>> TransitionWeights for [R] {
+  #[inline]
+  fn state_count(&self) -> usize {
+    self.len()
+  }
+  #[inline]
+  fn row(&self, state: usize) -> &[u32] {
+    self[state].as_ref()
+  }
+}
+
+impl<R: AsRef<[u32]>, const N: usize> TransitionWeights for [R; N] {
+  #[inline]
+  fn state_count(&self) -> usize {
+    N
+  }
+  #[inline]
+  fn row(&self, state: usize) -> &[u32] {
+    self[state].as_ref()
+  }
+}
+
+#[cfg(feature = "std")]
+impl<R: AsRef<[u32]>> TransitionWeights for Vec<R> {
+  #[inline]
+  fn state_count(&self) -> usize {
+    self.len()
+  }
+  #[inline]
+  fn row(&self, state: usize) -> &[u32] {
+    self[state].as_ref()
+  }
+}
+
+impl<T: TransitionWeights + ?Sized> TransitionWeights for &T {
+  #[inline]
+  fn state_count(&self) -> usize {
+    T::state_count(self)
+  }
+  #[inline]
+  fn row(&self, state: usize) -> &[u32] {
+    T::row(self, state)
+  }
+}
+
+/// Transition weights stored as one flat buffer, one row after another.
+///
+/// * The buffer can be an array or a slice (anything that's `AsRef<[u32]>`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FlatWeights<B> {
+  weights: B,
+  states: usize,
+}
+
+impl<B: AsRef<[u32]>> FlatWeights<B> {
+  /// Constructs a new `FlatWeights`.
+  ///
+  /// ## Panics
+  /// * If the buffer's length isn't `states * states`.
+  #[inline]
+  pub fn new(weights: B, states: usize) -> Self {
+    Self::try_new(weights, states).expect("FlatWeights::new> Invalid parameters.")
+  }
+
+  /// Constructs a new `FlatWeights`, or `None` on failure.
+  ///
+  /// ## Failure
+  /// * If the buffer's length isn't `states * states`.
+  #[inline]
+  pub fn try_new(weights: B, states: usize) -> Option<Self> {
+    if states.checked_mul(states) == Some(weights.as_ref().len()) {
+      Some(Self { weights, states })
+    } else {
+      None
+    }
+  }
+
+  /// The buffer of weights.
+  #[inline]
+  pub fn weights(&self) -> &[u32] {
+    self.weights.as_ref()
+  }
+}
+
+impl<B: AsRef<[u32]>> TransitionWeights for FlatWeights<B> {
+  #[inline]
+  fn state_count(&self) -> usize {
+    self.states
+  }
+  #[inline]
+  fn row(&self, state: usize) -> &[u32] {
+    assert!(state < self.states, "FlatWeights::row> State out of bounds.");
+    &self.weights.as_ref()[state * self.states..][..self.states]
+  }
+}
+
+/// A Markov chain: a current state, which moves to a new state at random with
+/// chances that depend only on the current state.
+///
+/// * States are indexes, `0 .. state_count`.
+/// * Each step moves from state `i` to state `j` with chance proportional to
+///   the weight at row `i`, column `j` of the [`TransitionWeights`]. A weight
+///   of 0 means that move never happens. The weights can stay as plain
+///   integers, there's no need to make each row add up to any particular
+///   amount.
+/// * Each step takes one bounded `u64` draw and a scan of the current row, the
+///   same as [`Gen32::pick_weighted`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MarkovChain<M> {
+  weights: M,
+  state: usize,
+}
+
+impl<M: TransitionWeights> MarkovChain<M> {
+  /// Constructs a new chain in the `start` state.
+  ///
+  /// ## Panics
+  /// * See [`try_new`](Self::try_new).
+  #[inline]
+  pub fn new(weights: M, start: usize) -> Self {
+    Self::try_new(weights, start).expect("MarkovChain::new> Invalid parameters.")
+  }
+
+  /// Constructs a new chain in the `start` state, or `None` on failure.
+  ///
+  /// ## Failure
+  /// * If there are no states.
+  /// * If any row's length isn't the number of states.
+  /// * If any row's weights add up to 0 (there'd be nowhere to go from that
+  ///   state). To have a state that the chain stays in, give it weight only on
+  ///   itself.
+  /// * If `start` isn't a valid state.
+  #[inline]
+  pub fn try_new(weights: M, start: usize) -> Option<Self> {
+    let n = weights.state_count();
+    if n == 0 || start >= n {
+      return None;
+    }
+    for i in 0..n {
+      let row = weights.row(i);
+      if row.len() != n || row.iter().all(|&w| w == 0) {
+        return None;
+      }
+    }
+    Some(Self { weights, state: start })
+  }
+
+  /// The transition weights.
+  #[inline]
+  pub const fn weights(&self) -> &M {
+    &self.weights
+  }
+
+  /// The number of states.
+  #[inline]
+  pub fn state_count(&self) -> usize {
+    self.weights.state_count()
+  }
+
+  /// The current state.
+  #[inline]
+  pub const fn state(&self) -> usize {
+    self.state
+  }
+
+  /// Sets the current state.
+  ///
+  /// ## Panics
+  /// * If `state` isn't a valid state.
+  #[inline]
+  pub fn set_state(&mut self, state: usize) {
+    assert!(state < self.state_count(), "MarkovChain::set_state> State out of bounds.");
+    self.state = state;
+  }
+
+  /// Picks a state to move to from `from`, without changing the current state.
+  ///
+  /// ## Panics
+  /// * If `from` isn't a valid state.
+  #[inline]
+  pub fn next_state_from<G: Gen32 + ?Sized>(&self, from: usize, gen: &mut G) -> usize {
+    let row = self.weights.row(from);
+    let total: u64 = row.iter().map(|&w| w as u64).sum();
+    let target = next_bounded_u64(gen, total);
+    let mut running = 0_u64;
+    for (i, &w) in row.iter().enumerate() {
+      running += w as u64;
+      if target < running {
+        return i;
+      }
+    }
+    unreachable!()
+  }
+
+  /// Moves to a new state, and gives that state.
+  #[inline]
+  pub fn step<G: Gen32 + ?Sized>(&mut self, gen: &mut G) -> usize {
+    self.state = self.next_state_from(self.state, gen);
+    self.state
+  }
+
+  /// Moves `out.len()` times, writing each new state into `out`.
+  #[inline]
+  pub fn fill_steps<G: Gen32 + ?Sized>(&mut self, gen: &mut G, out: &mut [usize]) {
+    for o in out.iter_mut() {
+      *o = self.step(gen);
+    }
+  }
+}
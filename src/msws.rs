@@ -0,0 +1,77 @@
+use super::*;
+
+/// Bernard Widynski's [Middle Square Weyl
+/// Sequence](https://arxiv.org/abs/1704.00358) generator, with 192 bits of
+/// state and 32 bits of output per step.
+///
+/// * Each step squares the state, adds the next value of a Weyl sequence, and
+///   takes the middle 32 bits.
+/// * The Weyl sequence's increment acts as a stream selector: every increment
+///   gives a different sequence, each with a period of at least `2^64`. The
+///   increment must be odd and should have a fairly irregular bit pattern,
+///   which [`seed`](Self::seed) ensures by hashing the stream value.
+/// * If you want to exactly save/restore a generator use the `Into` and `From`
+///   impls to convert the generator into and from a `[u64; 3]` (the square, the
+///   Weyl value, and the Weyl increment).
+/// * The methods on this type are quite minimal. You're expected to use the
+///   [`Gen32`] trait to provide most of the useful operations.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Msws {
+  #[cfg_attr(feature = "serde", serde(rename = "x_v1"))]
+  x: u64,
+  #[cfg_attr(feature = "serde", serde(rename = "w_v1"))]
+  w: u64,
+  #[cfg_attr(feature = "serde", serde(rename = "s_v1"))]
+  s: u64,
+}
+
+impl Msws {
+  /// Seed a new generator.
+  ///
+  /// The `stream` value selects the Weyl increment, so different streams give
+  /// different sequences even with the same `seed`.
+  pub const fn seed(seed: u64, stream: u64) -> Self {
+    Self { x: seed, w: 0, s: splitmix64_mix(stream) | 1 }
+  }
+
+  /// Gets the next 32-bits of output.
+  #[inline]
+  pub fn next_u32(&mut self) -> u32 {
+    self.w = self.w.wrapping_add(self.s);
+    self.x = self.x.wrapping_mul(self.x).wrapping_add(self.w);
+    self.x = self.x.rotate_left(32);
+    self.x as u32
+  }
+
+  /// The Weyl increment being used as this generator's stream.
+  #[inline]
+  pub const fn weyl_increment(&self) -> u64 {
+    self.s
+  }
+}
+
+impl Default for Msws {
+  fn default() -> Self {
+    const THE_DEFAULT: Msws = Msws::seed(DEFAULT_PCG_SEED as _, DEFAULT_PCG_INC as _);
+    THE_DEFAULT
+  }
+}
+
+impl From<[u64; 3]> for Msws {
+  fn from([x, w, s]: [u64; 3]) -> Self {
+    Self { x, w, s }
+  }
+}
+
+impl From<Msws> for [u64; 3] {
+  fn from(msws: Msws) -> Self {
+    [msws.x, msws.w, msws.s]
+  }
+}
+
+impl Gen32 for Msws {
+  fn next_u32(&mut self) -> u32 {
+    Msws::next_u32(self)
+  }
+}
@@ -0,0 +1,130 @@
+use super::*;
+
+const MT_N: usize = 624;
+const MT_M: usize = 397;
+const MT_MATRIX_A: u32 = 0x9908b0df;
+const MT_UPPER_MASK: u32 = 0x8000_0000;
+const MT_LOWER_MASK: u32 = 0x7fff_ffff;
+
+/// The 32-bit [Mersenne Twister](https://en.wikipedia.org/wiki/Mersenne_Twister)
+/// (MT19937), with 624 words of state and 32 bits of output per step.
+///
+/// * This is here for reproducing sequences from other tools (the C++
+///   `std::mt19937`, Python's `random` module, and so on). The output exactly
+///   matches the 2002 reference implementation for both of the reference
+///   seeding routines, [`seed_u32`](Self::seed_u32) (`init_genrand`) and
+///   [`seed_from_slice`](Self::seed_from_slice) (`init_by_array`).
+/// * For new code, prefer a generator with a smaller state. This one is about
+///   2.5k in size and fails some modern statistical tests.
+/// * The methods on this type are quite minimal. You're expected to use the
+///   [`Gen32`] trait to provide most of the useful operations.
+///
+/// ```
+/// # use randomize::Mt19937;
+/// let mut mt = Mt19937::seed_u32(5489);
+/// assert_eq!(mt.next_u32(), 3499211612);
+/// assert_eq!(mt.next_u32(), 581869302);
+///
+/// let mut mt = Mt19937::seed_from_slice(&[0x123, 0x234, 0x345, 0x456]);
+/// assert_eq!(mt.next_u32(), 1067595299);
+/// assert_eq!(mt.next_u32(), 955945823);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Mt19937 {
+  #[cfg_attr(feature = "serde", serde(rename = "mt_v1", with = "crate::serde_array"))]
+  mt: [u32; MT_N],
+  #[cfg_attr(feature = "serde", serde(rename = "index_v1"))]
+  index: usize,
+}
+
+impl Mt19937 {
+  /// Seed a new generator from a single `u32` (the reference `init_genrand`).
+  pub const fn seed_u32(seed: u32) -> Self {
+    let mut mt = [0_u32; MT_N];
+    mt[0] = seed;
+    let mut i = 1;
+    while i < MT_N {
+      let prev = mt[i - 1];
+      mt[i] = 1812433253_u32.wrapping_mul(prev ^ (prev >> 30)).wrapping_add(i as u32);
+      i += 1;
+    }
+    Self { mt, index: MT_N }
+  }
+
+  /// Seed a new generator from a slice of `u32` (the reference
+  /// `init_by_array`).
+  ///
+  /// An empty slice is treated the same as `&[0]`.
+  pub fn seed_from_slice(key: &[u32]) -> Self {
+    let key = if key.is_empty() { &[0][..] } else { key };
+    let mut out = Self::seed_u32(19650218);
+    let mt = &mut out.mt;
+    let mut i = 1;
+    let mut j = 0;
+    for _ in 0..MT_N.max(key.len()) {
+      let prev = mt[i - 1];
+      mt[i] = (mt[i] ^ (prev ^ (prev >> 30)).wrapping_mul(1664525))
+        .wrapping_add(key[j])
+        .wrapping_add(j as u32);
+      i += 1;
+      j += 1;
+      if i >= MT_N {
+        mt[0] = mt[MT_N - 1];
+        i = 1;
+      }
+      if j >= key.len() {
+        j = 0;
+      }
+    }
+    for _ in 0..(MT_N - 1) {
+      let prev = mt[i - 1];
+      mt[i] = (mt[i] ^ (prev ^ (prev >> 30)).wrapping_mul(1566083941)).wrapping_sub(i as u32);
+      i += 1;
+      if i >= MT_N {
+        mt[0] = mt[MT_N - 1];
+        i = 1;
+      }
+    }
+    mt[0] = 0x8000_0000;
+    out
+  }
+
+  /// Regenerates all 624 words of state.
+  fn twist(&mut self) {
+    let mt = &mut self.mt;
+    for i in 0..MT_N {
+      let y = (mt[i] & MT_UPPER_MASK) | (mt[(i + 1) % MT_N] & MT_LOWER_MASK);
+      let mag = if (y & 1) != 0 { MT_MATRIX_A } else { 0 };
+      mt[i] = mt[(i + MT_M) % MT_N] ^ (y >> 1) ^ mag;
+    }
+    self.index = 0;
+  }
+
+  /// Gets the next 32-bits of output.
+  #[inline]
+  pub fn next_u32(&mut self) -> u32 {
+    if self.index >= MT_N {
+      self.twist();
+    }
+    let mut y = self.mt[self.index];
+    self.index += 1;
+    y ^= y >> 11;
+    y ^= (y << 7) & 0x9d2c5680;
+    y ^= (y << 15) & 0xefc60000;
+    y ^ (y >> 18)
+  }
+}
+
+impl Default for Mt19937 {
+  /// The reference default seed, `5489`.
+  fn default() -> Self {
+    Self::seed_u32(5489)
+  }
+}
+
+impl Gen32 for Mt19937 {
+  fn next_u32(&mut self) -> u32 {
+    Mt19937::next_u32(self)
+  }
+}
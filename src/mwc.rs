@@ -0,0 +1,176 @@
+use super::*;
+
+const MWC32_MULTIPLIER: u32 = 4294957665;
+const CMWC4096_MULTIPLIER: u32 = 18782;
+
+/// A lag-1 [multiply-with-carry](https://en.wikipedia.org/wiki/Multiply-with-carry_pseudorandom_number_generator)
+/// generator, with 64 bits of state and 32 bits of output per step.
+///
+/// * Each step is a single `32x32->64` multiply plus an add, which suits retro
+///   and embedded targets well.
+/// * The period is about `2^63`.
+/// * If you want to exactly save/restore a generator use the `Into` and `From`
+///   impls to convert the generator into and from a `[u32; 2]` (the value and
+///   the carry).
+/// * The methods on this type are quite minimal. You're expected to use the
+///   [`Gen32`] trait to provide most of the useful operations.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Mwc32 {
+  #[cfg_attr(feature = "serde", serde(rename = "x_v1"))]
+  x: u32,
+  #[cfg_attr(feature = "serde", serde(rename = "c_v1"))]
+  c: u32,
+}
+
+impl Mwc32 {
+  /// Seed a new generator.
+  ///
+  /// The carry must be in `1 .. a-1` for the generator to have its full
+  /// period, so the `carry` value given is wrapped into that range.
+  pub const fn seed(seed: u32, carry: u32) -> Self {
+    Self { x: seed, c: carry % (MWC32_MULTIPLIER - 2) + 1 }
+  }
+
+  /// Gets the next 32-bits of output.
+  #[inline]
+  pub fn next_u32(&mut self) -> u32 {
+    let t = (MWC32_MULTIPLIER as u64).wrapping_mul(self.x as u64).wrapping_add(self.c as u64);
+    self.x = t as u32;
+    self.c = (t >> 32) as u32;
+    self.x
+  }
+}
+
+impl Default for Mwc32 {
+  fn default() -> Self {
+    const THE_DEFAULT: Mwc32 = Mwc32::seed(DEFAULT_PCG_SEED as _, DEFAULT_PCG_INC as _);
+    THE_DEFAULT
+  }
+}
+
+impl From<[u32; 2]> for Mwc32 {
+  fn from([x, c]: [u32; 2]) -> Self {
+    Self { x, c }
+  }
+}
+
+impl From<Mwc32> for [u32; 2] {
+  fn from(mwc: Mwc32) -> Self {
+    [mwc.x, mwc.c]
+  }
+}
+
+impl Gen32 for Mwc32 {
+  fn next_u32(&mut self) -> u32 {
+    Mwc32::next_u32(self)
+  }
+}
+
+/// A lag-`R` [complementary multiply-with-carry](https://en.wikipedia.org/wiki/Multiply-with-carry_pseudorandom_number_generator#Complementary-multiply-with-carry_generators)
+/// generator, with `R` words of state and 32 bits of output per step.
+///
+/// * Like [`Mwc32`], each step is one `32x32->64` multiply and some adds, but
+///   the period grows with the lag. The standard `Cmwc<4096>` ([`Cmwc4096`])
+///   has a period of about `2^131086`, using 16k of state.
+/// * Making a `Cmwc4096` (with `seed`, `Default`, or `from_entropy`) builds all
+///   16k on the stack, which is more than small stacks (such as the GBA's) have
+///   room for. On those targets use a smaller lag.
+/// * Each lag needs its own multiplier. [`Cmwc4096::seed`] uses Marsaglia's
+///   multiplier for lag 4096, and
+///   [`seed_with_multiplier`](Self::seed_with_multiplier) accepts any other lag
+///   and multiplier pair you've found elsewhere.
+/// * The methods on this type are quite minimal. You're expected to use the
+///   [`Gen32`] trait to provide most of the useful operations.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cmwc<const R: usize> {
+  #[cfg_attr(feature = "serde", serde(rename = "q_v1", with = "crate::serde_array"))]
+  q: [u32; R],
+  #[cfg_attr(feature = "serde", serde(rename = "c_v1"))]
+  c: u32,
+  #[cfg_attr(feature = "serde", serde(rename = "a_v1"))]
+  a: u32,
+  #[cfg_attr(feature = "serde", serde(rename = "i_v1"))]
+  i: usize,
+}
+
+/// The standard lag-4096 complementary multiply-with-carry generator.
+pub type Cmwc4096 = Cmwc<4096>;
+
+impl<const R: usize> Cmwc<R> {
+  /// Makes a generator from a multiplier, the lag table, and the carry.
+  ///
+  /// The multiplier `a` must be one where `a * 2^(32 * R) + 1` is a safe
+  /// prime, otherwise the period is much shorter than it should be. The carry
+  /// is wrapped into `0 .. a`.
+  ///
+  /// ## Panics
+  /// * If `R` or `a` is 0.
+  pub fn seed_with_multiplier(a: u32, q: [u32; R], carry: u32) -> Self {
+    assert!(R > 0, "Cmwc::seed_with_multiplier> Lag must be non-zero.");
+    assert!(a > 0, "Cmwc::seed_with_multiplier> Multiplier must be non-zero.");
+    Self { q, c: carry % a, a, i: R - 1 }
+  }
+
+  /// Gets the next 32-bits of output.
+  #[inline]
+  pub fn next_u32(&mut self) -> u32 {
+    // Reduced first so that a bad deserialized index can't overflow.
+    self.i = (self.i % R + 1) % R;
+    let t = (self.a as u64).wrapping_mul(self.q[self.i] as u64).wrapping_add(self.c as u64);
+    self.c = (t >> 32) as u32;
+    let mut x = (t as u32).wrapping_add(self.c);
+    if x < self.c {
+      x = x.wrapping_add(1);
+      self.c = self.c.wrapping_add(1);
+    }
+    self.q[self.i] = 0xffff_fffe_u32.wrapping_sub(x);
+    self.q[self.i]
+  }
+}
+
+impl Cmwc<4096> {
+  /// Seed a new generator.
+  ///
+  /// The lag table and the carry are filled in from a [`SplitMix64`].
+  ///
+  /// This builds the 16k lag table on the stack, which is too large for small
+  /// stacks such as the GBA's.
+  pub fn seed(seed: u64) -> Self {
+    let mut sm = SplitMix64::seed(seed);
+    let mut q = [0_u32; 4096];
+    for pair in q.chunks_exact_mut(2) {
+      let x = sm.next_u64();
+      pair[0] = x as u32;
+      pair[1] = (x >> 32) as u32;
+    }
+    let carry = sm.next_u64() as u32;
+    Self::seed_with_multiplier(CMWC4096_MULTIPLIER, q, carry)
+  }
+}
+
+impl Default for Cmwc<4096> {
+  fn default() -> Self {
+    Self::seed(DEFAULT_PCG_SEED as _)
+  }
+}
+
+impl<const R: usize> Gen32 for Cmwc<R> {
+  fn next_u32(&mut self) -> u32 {
+    Cmwc::next_u32(self)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn out_of_range_index() {
+    let mut bad = Cmwc::<4> { q: [1, 2, 3, 4], c: 5, a: 7, i: usize::MAX };
+    let mut good = Cmwc::<4> { q: [1, 2, 3, 4], c: 5, a: 7, i: usize::MAX % 4 };
+    assert_eq!(bad.next_u32(), good.next_u32());
+    assert_eq!(bad, good);
+  }
+}
@@ -0,0 +1,198 @@
+//! Coherent noise, for procedural textures and terrain.
+//!
+//! Each noise type is set up once from a [`Gen32`] (or a seed), and after that
+//! sampling it is a pure function of the position. Float math is done with
+//! `libm`, so everything here works in `no_std`.
+//!
+//! Some types also have a fixed-point path, for targets with no FPU (such as
+//! the GBA). Fixed-point values are 16.16, so `1.0` is `0x1_0000`.
+
+use super::*;
+
+mod diamond_square;
+pub use diamond_square::*;
+
+mod fbm;
+pub use fbm::*;
+
+mod fill;
+pub use fill::*;
+
+mod hash_noise;
+pub use hash_noise::*;
+
+mod perlin;
+pub use perlin::*;
+
+mod simplex;
+pub use simplex::*;
+
+mod value;
+pub use value::*;
+
+mod worley;
+pub use worley::*;
+
+/// A noise field that can be sampled in 2D.
+///
+/// This lets code such as [`Fbm`] work with any kind of noise.
+pub trait Noise2D {
+  /// Samples the noise at a point.
+  fn sample_2d(&self, x: f32, y: f32) -> f32;
+}
+
+/// A noise field that can be sampled in 3D.
+///
+/// This lets code such as [`Fbm`] work with any kind of noise.
+pub trait Noise3D {
+  /// Samples the noise at a point.
+  fn sample_3d(&self, x: f32, y: f32, z: f32) -> f32;
+}
+
+impl Noise2D for Perlin {
+  #[inline]
+  fn sample_2d(&self, x: f32, y: f32) -> f32 {
+    Perlin::sample_2d(self, x, y)
+  }
+}
+
+impl Noise3D for Perlin {
+  #[inline]
+  fn sample_3d(&self, x: f32, y: f32, z: f32) -> f32 {
+    Perlin::sample_3d(self, x, y, z)
+  }
+}
+
+impl Noise2D for Simplex {
+  #[inline]
+  fn sample_2d(&self, x: f32, y: f32) -> f32 {
+    Simplex::sample_2d(self, x, y)
+  }
+}
+
+impl Noise3D for Simplex {
+  #[inline]
+  fn sample_3d(&self, x: f32, y: f32, z: f32) -> f32 {
+    Simplex::sample_3d(self, x, y, z)
+  }
+}
+
+impl Noise2D for ValueNoise {
+  #[inline]
+  fn sample_2d(&self, x: f32, y: f32) -> f32 {
+    ValueNoise::sample_2d(*self, x, y)
+  }
+}
+
+impl Noise3D for ValueNoise {
+  #[inline]
+  fn sample_3d(&self, x: f32, y: f32, z: f32) -> f32 {
+    ValueNoise::sample_3d(*self, x, y, z)
+  }
+}
+
+/// Gives `f1`, the distance to the nearest feature point.
+impl Noise2D for Worley {
+  #[inline]
+  fn sample_2d(&self, x: f32, y: f32) -> f32 {
+    Worley::sample_2d(*self, x, y).0
+  }
+}
+
+/// Gives `f1`, the distance to the nearest feature point.
+impl Noise3D for Worley {
+  #[inline]
+  fn sample_3d(&self, x: f32, y: f32, z: f32) -> f32 {
+    Worley::sample_3d(*self, x, y, z).0
+  }
+}
+
+/// `1.0` in 16.16 fixed-point.
+const FX_ONE: i32 = 1 << 16;
+
+/// A random permutation of `0 ..= 255`, used to hash lattice points.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct PermTable([u8; 256]);
+
+impl PermTable {
+  fn new<G: Gen32>(gen: &mut G) -> Self {
+    let mut perm = [0_u8; 256];
+    for (i, p) in perm.iter_mut().enumerate() {
+      *p = i as u8;
+    }
+    gen.shuffle(&mut perm);
+    Self(perm)
+  }
+
+  #[inline]
+  fn hash(&self, x: i32) -> usize {
+    self.0[(x & 255) as usize] as usize
+  }
+
+  #[inline]
+  fn hash2(&self, x: i32, y: i32) -> usize {
+    self.0[(self.hash(x) + (y & 255) as usize) & 255] as usize
+  }
+
+  #[inline]
+  fn hash3(&self, x: i32, y: i32, z: i32) -> usize {
+    self.0[(self.hash2(x, y) + (z & 255) as usize) & 255] as usize
+  }
+}
+
+/// The quintic fade curve, `6t^5 - 15t^4 + 10t^3`.
+#[inline]
+fn fade(t: f32) -> f32 {
+  t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+/// The quintic fade curve in 16.16 fixed-point, for `t` in `0 ..= 1.0`.
+#[inline]
+fn fade_fx(t: i32) -> i32 {
+  let t = t as i64;
+  let one = FX_ONE as i64;
+  let inner = ((t * (t * 6 - 15 * one)) >> 16) + 10 * one;
+  let t3 = (((t * t) >> 16) * t) >> 16;
+  ((t3 * inner) >> 16) as i32
+}
+
+#[inline]
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+  a + (b - a) * t
+}
+
+#[inline]
+fn lerp_fx(a: i32, b: i32, t: i32) -> i32 {
+  a + (((b - a) as i64 * t as i64) >> 16) as i32
+}
+
+/// 2D gradients are the 8 directions to the edges and corners of a square.
+#[inline]
+fn grad2(hash: usize, x: f32, y: f32) -> f32 {
+  match hash & 7 {
+    0 => x + y,
+    1 => -x + y,
+    2 => x - y,
+    3 => -x - y,
+    4 => x,
+    5 => -x,
+    6 => y,
+    _ => -y,
+  }
+}
+
+/// 3D gradients are the 12 directions to the edges of a cube (with 4 of them
+/// doubled up to make 16).
+#[inline]
+fn grad3(hash: usize, x: f32, y: f32, z: f32) -> f32 {
+  let h = hash & 15;
+  let u = if h < 8 { x } else { y };
+  let v = if h < 4 {
+    y
+  } else if h == 12 || h == 14 {
+    x
+  } else {
+    z
+  };
+  (if h & 1 == 0 { u } else { -u }) + (if h & 2 == 0 { v } else { -v })
+}
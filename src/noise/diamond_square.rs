@@ -0,0 +1,85 @@
+use super::*;
+
+/// Fills a square heightmap using the diamond-square algorithm.
+///
+/// * `buf` is `size` rows of `size` heights, and `size` must be a power of two
+///   plus one (eg: 129 or 257).
+/// * The four corners get random heights in `-roughness ..= roughness`. Then
+///   each pass sets the middle of every square ("diamond" step) and the middle
+///   of every edge ("square" step) to the average of its neighbors, plus a
+///   random offset. The offset range starts at `roughness` and halves with each
+///   pass, so the largest features come from the first passes.
+/// * Heights are `i32`, and the math is all integer, so this is fast even with
+///   no FPU. Heights stay within `-2 * roughness ..= 2 * roughness` (and
+///   saturate at the `i32` limits).
+///
+/// ## Panics
+/// * If `size` isn't a power of two plus one, or is less than 2.
+/// * If `buf.len()` isn't `size * size`.
+pub fn diamond_square<G: Gen32 + ?Sized>(
+  gen: &mut G, buf: &mut [i32], size: usize, roughness: u32,
+) {
+  assert!(
+    size >= 2 && (size - 1).is_power_of_two(),
+    "diamond_square> The size must be a power of two plus one."
+  );
+  assert!(
+    size.checked_mul(size) == Some(buf.len()),
+    "diamond_square> The buffer must be `size * size` long."
+  );
+  let offset = |gen: &mut G, amp: u32| -> i64 {
+    next_bounded_u64(gen, 2 * amp as u64 + 1) as i64 - amp as i64
+  };
+  let idx = |x: usize, y: usize| y * size + x;
+  let last = size - 1;
+  let mut amp = roughness;
+  for (x, y) in [(0, 0), (last, 0), (0, last), (last, last)] {
+    buf[idx(x, y)] = saturate(offset(gen, amp));
+  }
+  let mut step = last;
+  while step > 1 {
+    let half = step / 2;
+    amp /= 2;
+    // Diamond step: the middle of each square.
+    for y in (half..size).step_by(step) {
+      for x in (half..size).step_by(step) {
+        let sum = buf[idx(x - half, y - half)] as i64
+          + buf[idx(x + half, y - half)] as i64
+          + buf[idx(x - half, y + half)] as i64
+          + buf[idx(x + half, y + half)] as i64;
+        buf[idx(x, y)] = saturate(sum / 4 + offset(gen, amp));
+      }
+    }
+    // Square step: the middle of each edge, which might be on the border and
+    // so have only three neighbors.
+    for y in (0..size).step_by(half) {
+      let start = if (y / half) & 1 == 0 { half } else { 0 };
+      for x in (start..size).step_by(step) {
+        let (mut sum, mut count) = (0_i64, 0_i64);
+        if x >= half {
+          sum += buf[idx(x - half, y)] as i64;
+          count += 1;
+        }
+        if x + half < size {
+          sum += buf[idx(x + half, y)] as i64;
+          count += 1;
+        }
+        if y >= half {
+          sum += buf[idx(x, y - half)] as i64;
+          count += 1;
+        }
+        if y + half < size {
+          sum += buf[idx(x, y + half)] as i64;
+          count += 1;
+        }
+        buf[idx(x, y)] = saturate(sum / count + offset(gen, amp));
+      }
+    }
+    step = half;
+  }
+}
+
+#[inline]
+fn saturate(x: i64) -> i32 {
+  x.clamp(i32::MIN as i64, i32::MAX as i64) as i32
+}
@@ -0,0 +1,125 @@
+use super::*;
+
+/// How far each octave is moved from the last, so that the octaves don't all
+/// line up at the origin (where gradient noise is always 0).
+const OCTAVE_SHIFT: f32 = 19.190_6;
+
+/// Fractal Brownian motion: several octaves of a noise, added together.
+///
+/// * Each octave samples the noise at a higher frequency (multiplied by the
+///   `lacunarity` each time) and a lower amplitude (multiplied by the `gain`
+///   each time). This adds fine detail on top of the broad shapes, which is
+///   what makes noise look like terrain or clouds.
+/// * The total is divided by the sum of the amplitudes, so the output has about
+///   the same range as the inner noise.
+/// * The defaults are 4 octaves, a lacunarity of 2.0, and a gain of 0.5.
+/// * `Fbm` is itself a [`Noise2D`] and [`Noise3D`] (when the inner noise is),
+///   so it can be used anywhere a noise can.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fbm<N> {
+  noise: N,
+  octaves: u32,
+  lacunarity: f32,
+  gain: f32,
+}
+
+impl<N> Fbm<N> {
+  /// Wraps a noise, with the default settings.
+  #[inline]
+  pub const fn new(noise: N) -> Self {
+    Self { noise, octaves: 4, lacunarity: 2.0, gain: 0.5 }
+  }
+
+  /// Sets the number of octaves.
+  ///
+  /// ## Panics
+  /// * If `octaves` is 0.
+  #[inline]
+  pub fn with_octaves(self, octaves: u32) -> Self {
+    assert!(octaves > 0, "Fbm::with_octaves> Must have at least one octave.");
+    Self { octaves, ..self }
+  }
+
+  /// Sets the frequency multiplier between octaves.
+  #[inline]
+  pub fn with_lacunarity(self, lacunarity: f32) -> Self {
+    Self { lacunarity, ..self }
+  }
+
+  /// Sets the amplitude multiplier between octaves.
+  #[inline]
+  pub fn with_gain(self, gain: f32) -> Self {
+    Self { gain, ..self }
+  }
+
+  /// The inner noise.
+  #[inline]
+  pub const fn noise(&self) -> &N {
+    &self.noise
+  }
+
+  /// The number of octaves.
+  #[inline]
+  pub const fn octaves(&self) -> u32 {
+    self.octaves
+  }
+
+  /// The frequency multiplier between octaves.
+  #[inline]
+  pub const fn lacunarity(&self) -> f32 {
+    self.lacunarity
+  }
+
+  /// The amplitude multiplier between octaves.
+  #[inline]
+  pub const fn gain(&self) -> f32 {
+    self.gain
+  }
+
+  /// Adds up the octaves, given a function to sample one octave at a
+  /// frequency and shift.
+  #[inline]
+  fn layer(&self, mut octave: impl FnMut(f32, f32) -> f32) -> f32 {
+    let (mut total, mut amp_sum) = (0.0, 0.0);
+    let (mut freq, mut amp) = (1.0, 1.0);
+    for i in 0..self.octaves {
+      total += amp * octave(freq, i as f32 * OCTAVE_SHIFT);
+      amp_sum += amp;
+      freq *= self.lacunarity;
+      amp *= self.gain;
+    }
+    total / amp_sum
+  }
+}
+
+impl<N: Noise2D> Fbm<N> {
+  /// Samples 2D noise.
+  #[inline]
+  pub fn sample_2d(&self, x: f32, y: f32) -> f32 {
+    self.layer(|freq, shift| self.noise.sample_2d(x * freq + shift, y * freq + shift))
+  }
+}
+
+impl<N: Noise3D> Fbm<N> {
+  /// Samples 3D noise.
+  #[inline]
+  pub fn sample_3d(&self, x: f32, y: f32, z: f32) -> f32 {
+    self.layer(|freq, shift| {
+      self.noise.sample_3d(x * freq + shift, y * freq + shift, z * freq + shift)
+    })
+  }
+}
+
+impl<N: Noise2D> Noise2D for Fbm<N> {
+  #[inline]
+  fn sample_2d(&self, x: f32, y: f32) -> f32 {
+    Fbm::sample_2d(self, x, y)
+  }
+}
+
+impl<N: Noise3D> Noise3D for Fbm<N> {
+  #[inline]
+  fn sample_3d(&self, x: f32, y: f32, z: f32) -> f32 {
+    Fbm::sample_3d(self, x, y, z)
+  }
+}
@@ -0,0 +1,84 @@
+use super::*;
+
+/// A pixel type that noise can be written into.
+///
+/// Noise values are taken to be in `-1.0 ..= 1.0`, and are clamped to that
+/// range before converting.
+pub trait NoisePixel: Sized {
+  /// Converts a noise value.
+  fn from_noise(value: f32) -> Self;
+
+  /// Converts a 16.16 fixed-point noise value.
+  fn from_noise_fx(value: i32) -> Self;
+}
+
+/// The value as-is (not clamped).
+impl NoisePixel for f32 {
+  #[inline]
+  fn from_noise(value: f32) -> Self {
+    value
+  }
+  #[inline]
+  fn from_noise_fx(value: i32) -> Self {
+    value as f32 * (1.0 / FX_ONE as f32)
+  }
+}
+
+/// Grayscale, from 0 (at -1.0) to 255 (at 1.0).
+impl NoisePixel for u8 {
+  #[inline]
+  fn from_noise(value: f32) -> Self {
+    ((value.clamp(-1.0, 1.0) + 1.0) * 127.5) as u8
+  }
+  #[inline]
+  fn from_noise_fx(value: i32) -> Self {
+    let v = value.clamp(-FX_ONE, FX_ONE) + FX_ONE;
+    ((v * 255) >> 17) as u8
+  }
+}
+
+/// Grayscale in RGB555 (the GBA's color format), from black (at -1.0) to white
+/// (at 1.0).
+impl NoisePixel for u16 {
+  #[inline]
+  fn from_noise(value: f32) -> Self {
+    gray555(((value.clamp(-1.0, 1.0) + 1.0) * 15.5) as u16)
+  }
+  #[inline]
+  fn from_noise_fx(value: i32) -> Self {
+    let v = value.clamp(-FX_ONE, FX_ONE) + FX_ONE;
+    gray555(((v * 31) >> 17) as u16)
+  }
+}
+
+/// The same 5-bit level in all three RGB555 channels.
+#[inline]
+const fn gray555(level: u16) -> u16 {
+  level | level << 5 | level << 10
+}
+
+/// Fills a buffer with 2D noise.
+///
+/// * The buffer is rows of `width` pixels. Pixel `(px, py)` samples the noise
+///   at `(px * scale, py * scale)`.
+/// * The sample positions are stepped along each row by adding, rather than
+///   multiplied out for each pixel.
+/// * If the buffer isn't a whole number of rows, the last row is partial.
+/// * For [`ValueNoise`] with no FPU, [`ValueNoise::fill_2d_fx`] is much faster.
+///
+/// ## Panics
+/// * If `width` is 0.
+#[inline]
+pub fn fill_noise_2d<T: NoisePixel, N: Noise2D + ?Sized>(
+  buf: &mut [T], width: usize, noise: &N, scale: f32,
+) {
+  assert!(width > 0, "fill_noise_2d> The width must be more than 0.");
+  for (py, row) in buf.chunks_mut(width).enumerate() {
+    let y = py as f32 * scale;
+    let mut x = 0.0;
+    for pixel in row.iter_mut() {
+      *pixel = T::from_noise(noise.sample_2d(x, y));
+      x += scale;
+    }
+  }
+}
@@ -0,0 +1,75 @@
+/// Multiplier to fold the `y` coordinate into a 1D position.
+const HASH_PRIME_Y: u32 = 198_491_317;
+/// Multiplier to fold the `z` coordinate into a 1D position.
+const HASH_PRIME_Z: u32 = 6_542_989;
+
+/// Position-indexed random bits: a hash of a 1D position and a seed.
+///
+/// * This is Squirrel Eiserloh's "Squirrel3" noise function. Each position
+///   gives an unrelated `u32`, with no generator state to carry around, so
+///   values can be looked up in any order (eg: "what's in chunk 7?") and always
+///   come out the same.
+/// * Nearby positions are *not* similar. For smooth noise, see
+///   [`ValueNoise`](crate::ValueNoise) (which is built on this).
+/// * The quality is fine for games, but not for statistics work.
+#[inline]
+#[must_use]
+pub const fn hash_noise_u32(position: i32, seed: u32) -> u32 {
+  let mut m = (position as u32).wrapping_mul(0xB529_7A4D);
+  m = m.wrapping_add(seed);
+  m ^= m >> 8;
+  m = m.wrapping_add(0x68E3_1DA4);
+  m ^= m << 8;
+  m = m.wrapping_mul(0x1B56_C4E9);
+  m ^= m >> 8;
+  m
+}
+
+/// Like [`hash_noise_u32`], for a 2D position.
+///
+/// The position is folded into 1D with a large prime multiplier.
+#[inline]
+#[must_use]
+pub const fn hash_noise_2d_u32(x: i32, y: i32, seed: u32) -> u32 {
+  hash_noise_u32(x.wrapping_add(y.wrapping_mul(HASH_PRIME_Y as i32)), seed)
+}
+
+/// Like [`hash_noise_u32`], for a 3D position.
+///
+/// The position is folded into 1D with large prime multipliers.
+#[inline]
+#[must_use]
+pub const fn hash_noise_3d_u32(x: i32, y: i32, z: i32, seed: u32) -> u32 {
+  let position = x
+    .wrapping_add(y.wrapping_mul(HASH_PRIME_Y as i32))
+    .wrapping_add(z.wrapping_mul(HASH_PRIME_Z as i32));
+  hash_noise_u32(position, seed)
+}
+
+/// Like [`hash_noise_u32`], but gives an `f32` in `0.0 .. 1.0`.
+#[inline]
+#[must_use]
+pub fn hash_noise_f32(position: i32, seed: u32) -> f32 {
+  hash_to_unit_f32(hash_noise_u32(position, seed))
+}
+
+/// Like [`hash_noise_2d_u32`], but gives an `f32` in `0.0 .. 1.0`.
+#[inline]
+#[must_use]
+pub fn hash_noise_2d_f32(x: i32, y: i32, seed: u32) -> f32 {
+  hash_to_unit_f32(hash_noise_2d_u32(x, y, seed))
+}
+
+/// Like [`hash_noise_3d_u32`], but gives an `f32` in `0.0 .. 1.0`.
+#[inline]
+#[must_use]
+pub fn hash_noise_3d_f32(x: i32, y: i32, z: i32, seed: u32) -> f32 {
+  hash_to_unit_f32(hash_noise_3d_u32(x, y, z, seed))
+}
+
+/// The same conversion as
+/// [`Gen32::next_f32_unit`](crate::Gen32::next_f32_unit).
+#[inline]
+fn hash_to_unit_f32(h: u32) -> f32 {
+  (h >> 8) as f32 * (1.0 / (1_u32 << 24) as f32)
+}
@@ -0,0 +1,162 @@
+use super::*;
+
+/// Ken Perlin's "improved" gradient noise, in 1, 2, or 3 dimensions.
+///
+/// * The permutation table is made from a [`Gen32`] when you call
+///   [`new`](Self::new), so each generator state gives a different noise field.
+/// * The output is 0 at every integer lattice point, and is roughly in `-1.0
+///   ..= 1.0` (rarely reaching the ends).
+/// * The noise repeats every 256 units on each axis.
+/// * The `_fx` methods work in 16.16 fixed-point, for targets with no FPU. They
+///   sample the same field as the float methods (within rounding).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Perlin {
+  perm: PermTable,
+}
+
+impl Perlin {
+  /// Makes a new noise field.
+  #[inline]
+  pub fn new<G: Gen32>(gen: &mut G) -> Self {
+    Self { perm: PermTable::new(gen) }
+  }
+
+  /// Samples 1D noise.
+  #[inline]
+  pub fn sample_1d(&self, x: f32) -> f32 {
+    let x0 = libm::floorf(x);
+    let xi = x0 as i32;
+    let xf = x - x0;
+    let a = grad1(self.perm.hash(xi), xf);
+    let b = grad1(self.perm.hash(xi.wrapping_add(1)), xf - 1.0);
+    lerp(a, b, fade(xf))
+  }
+
+  /// Samples 2D noise.
+  #[inline]
+  pub fn sample_2d(&self, x: f32, y: f32) -> f32 {
+    let (x0, y0) = (libm::floorf(x), libm::floorf(y));
+    let (xi, yi) = (x0 as i32, y0 as i32);
+    let (xf, yf) = (x - x0, y - y0);
+    let (xi1, yi1) = (xi.wrapping_add(1), yi.wrapping_add(1));
+    let aa = grad2(self.perm.hash2(xi, yi), xf, yf);
+    let ba = grad2(self.perm.hash2(xi1, yi), xf - 1.0, yf);
+    let ab = grad2(self.perm.hash2(xi, yi1), xf, yf - 1.0);
+    let bb = grad2(self.perm.hash2(xi1, yi1), xf - 1.0, yf - 1.0);
+    let u = fade(xf);
+    lerp(lerp(aa, ba, u), lerp(ab, bb, u), fade(yf))
+  }
+
+  /// Samples 3D noise.
+  #[inline]
+  pub fn sample_3d(&self, x: f32, y: f32, z: f32) -> f32 {
+    let (x0, y0, z0) = (libm::floorf(x), libm::floorf(y), libm::floorf(z));
+    let (xi, yi, zi) = (x0 as i32, y0 as i32, z0 as i32);
+    let (xf, yf, zf) = (x - x0, y - y0, z - z0);
+    let (xi1, yi1, zi1) = (xi.wrapping_add(1), yi.wrapping_add(1), zi.wrapping_add(1));
+    let (u, v, w) = (fade(xf), fade(yf), fade(zf));
+    let aaa = grad3(self.perm.hash3(xi, yi, zi), xf, yf, zf);
+    let baa = grad3(self.perm.hash3(xi1, yi, zi), xf - 1.0, yf, zf);
+    let aba = grad3(self.perm.hash3(xi, yi1, zi), xf, yf - 1.0, zf);
+    let bba = grad3(self.perm.hash3(xi1, yi1, zi), xf - 1.0, yf - 1.0, zf);
+    let aab = grad3(self.perm.hash3(xi, yi, zi1), xf, yf, zf - 1.0);
+    let bab = grad3(self.perm.hash3(xi1, yi, zi1), xf - 1.0, yf, zf - 1.0);
+    let abb = grad3(self.perm.hash3(xi, yi1, zi1), xf, yf - 1.0, zf - 1.0);
+    let bbb = grad3(self.perm.hash3(xi1, yi1, zi1), xf - 1.0, yf - 1.0, zf - 1.0);
+    let near = lerp(lerp(aaa, baa, u), lerp(aba, bba, u), v);
+    let far = lerp(lerp(aab, bab, u), lerp(abb, bbb, u), v);
+    lerp(near, far, w)
+  }
+
+  /// Samples 1D noise in 16.16 fixed-point.
+  #[inline]
+  pub fn sample_1d_fx(&self, x: i32) -> i32 {
+    let (xi, xf) = (x >> 16, x & 0xFFFF);
+    let a = grad1_fx(self.perm.hash(xi), xf);
+    let b = grad1_fx(self.perm.hash(xi.wrapping_add(1)), xf - FX_ONE);
+    lerp_fx(a, b, fade_fx(xf))
+  }
+
+  /// Samples 2D noise in 16.16 fixed-point.
+  #[inline]
+  pub fn sample_2d_fx(&self, x: i32, y: i32) -> i32 {
+    let (xi, xf) = (x >> 16, x & 0xFFFF);
+    let (yi, yf) = (y >> 16, y & 0xFFFF);
+    let (xi1, yi1) = (xi.wrapping_add(1), yi.wrapping_add(1));
+    let aa = grad2_fx(self.perm.hash2(xi, yi), xf, yf);
+    let ba = grad2_fx(self.perm.hash2(xi1, yi), xf - FX_ONE, yf);
+    let ab = grad2_fx(self.perm.hash2(xi, yi1), xf, yf - FX_ONE);
+    let bb = grad2_fx(self.perm.hash2(xi1, yi1), xf - FX_ONE, yf - FX_ONE);
+    let u = fade_fx(xf);
+    lerp_fx(lerp_fx(aa, ba, u), lerp_fx(ab, bb, u), fade_fx(yf))
+  }
+
+  /// Samples 3D noise in 16.16 fixed-point.
+  #[inline]
+  pub fn sample_3d_fx(&self, x: i32, y: i32, z: i32) -> i32 {
+    let (xi, xf) = (x >> 16, x & 0xFFFF);
+    let (yi, yf) = (y >> 16, y & 0xFFFF);
+    let (zi, zf) = (z >> 16, z & 0xFFFF);
+    let (xi1, yi1, zi1) = (xi.wrapping_add(1), yi.wrapping_add(1), zi.wrapping_add(1));
+    let (u, v, w) = (fade_fx(xf), fade_fx(yf), fade_fx(zf));
+    let (xg, yg, zg) = (xf - FX_ONE, yf - FX_ONE, zf - FX_ONE);
+    let aaa = grad3_fx(self.perm.hash3(xi, yi, zi), xf, yf, zf);
+    let baa = grad3_fx(self.perm.hash3(xi1, yi, zi), xg, yf, zf);
+    let aba = grad3_fx(self.perm.hash3(xi, yi1, zi), xf, yg, zf);
+    let bba = grad3_fx(self.perm.hash3(xi1, yi1, zi), xg, yg, zf);
+    let aab = grad3_fx(self.perm.hash3(xi, yi, zi1), xf, yf, zg);
+    let bab = grad3_fx(self.perm.hash3(xi1, yi, zi1), xg, yf, zg);
+    let abb = grad3_fx(self.perm.hash3(xi, yi1, zi1), xf, yg, zg);
+    let bbb = grad3_fx(self.perm.hash3(xi1, yi1, zi1), xg, yg, zg);
+    let near = lerp_fx(lerp_fx(aaa, baa, u), lerp_fx(aba, bba, u), v);
+    let far = lerp_fx(lerp_fx(aab, bab, u), lerp_fx(abb, bbb, u), v);
+    lerp_fx(near, far, w)
+  }
+}
+
+/// 1D gradients are `±1.0`, doubled so that the output fills `-1.0 ..= 1.0`.
+#[inline]
+fn grad1(hash: usize, x: f32) -> f32 {
+  if hash & 1 == 0 {
+    2.0 * x
+  } else {
+    -2.0 * x
+  }
+}
+
+#[inline]
+fn grad1_fx(hash: usize, x: i32) -> i32 {
+  if hash & 1 == 0 {
+    2 * x
+  } else {
+    -2 * x
+  }
+}
+
+#[inline]
+fn grad2_fx(hash: usize, x: i32, y: i32) -> i32 {
+  match hash & 7 {
+    0 => x + y,
+    1 => -x + y,
+    2 => x - y,
+    3 => -x - y,
+    4 => x,
+    5 => -x,
+    6 => y,
+    _ => -y,
+  }
+}
+
+#[inline]
+fn grad3_fx(hash: usize, x: i32, y: i32, z: i32) -> i32 {
+  let h = hash & 15;
+  let u = if h < 8 { x } else { y };
+  let v = if h < 4 {
+    y
+  } else if h == 12 || h == 14 {
+    x
+  } else {
+    z
+  };
+  (if h & 1 == 0 { u } else { -u }) + (if h & 2 == 0 { v } else { -v })
+}
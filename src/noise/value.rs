@@ -0,0 +1,200 @@
+use super::*;
+
+/// Value noise: random values at the lattice points, smoothly blended.
+///
+/// * This needs only integer hashing and interpolation, so it's the cheapest
+///   noise here, and the `_fx` methods use no floats at all. That makes it the
+///   one to reach for on targets with no FPU.
+/// * The look is blobbier than gradient noise (such as [`Perlin`]), with
+///   visible bumps at the lattice points.
+/// * Lattice values are hashed from the position and the seed, so there's no
+///   table: this is 4 bytes, and doesn't repeat (until the `i32` lattice
+///   coordinates wrap).
+/// * The output is in `-1.0 .. 1.0`. The float and `_fx` methods sample the
+///   same field (within rounding).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ValueNoise {
+  seed: u32,
+}
+
+impl ValueNoise {
+  /// Makes a new noise field.
+  #[inline]
+  pub fn new<G: Gen32 + ?Sized>(gen: &mut G) -> Self {
+    Self::from_seed(gen.next_u32())
+  }
+
+  /// Makes a new noise field from a seed.
+  #[inline]
+  pub const fn from_seed(seed: u32) -> Self {
+    Self { seed }
+  }
+
+  /// The seed.
+  #[inline]
+  pub const fn seed(self) -> u32 {
+    self.seed
+  }
+
+  /// Samples 1D noise.
+  #[inline]
+  pub fn sample_1d(self, x: f32) -> f32 {
+    let x0 = libm::floorf(x);
+    let xi = x0 as i32;
+    let a = fx_to_f32(lattice_value(hash_noise_u32(xi, self.seed)));
+    let b = fx_to_f32(lattice_value(hash_noise_u32(xi.wrapping_add(1), self.seed)));
+    lerp(a, b, smoothstep(x - x0))
+  }
+
+  /// Samples 2D noise.
+  #[inline]
+  pub fn sample_2d(self, x: f32, y: f32) -> f32 {
+    let (x0, y0) = (libm::floorf(x), libm::floorf(y));
+    let (xi, yi) = (x0 as i32, y0 as i32);
+    let (u, v) = (smoothstep(x - x0), smoothstep(y - y0));
+    let value = |dx: i32, dy: i32| {
+      fx_to_f32(lattice_value(hash_noise_2d_u32(
+        xi.wrapping_add(dx),
+        yi.wrapping_add(dy),
+        self.seed,
+      )))
+    };
+    lerp(lerp(value(0, 0), value(1, 0), u), lerp(value(0, 1), value(1, 1), u), v)
+  }
+
+  /// Samples 3D noise.
+  #[inline]
+  pub fn sample_3d(self, x: f32, y: f32, z: f32) -> f32 {
+    let (x0, y0, z0) = (libm::floorf(x), libm::floorf(y), libm::floorf(z));
+    let (xi, yi, zi) = (x0 as i32, y0 as i32, z0 as i32);
+    let (u, v, w) = (smoothstep(x - x0), smoothstep(y - y0), smoothstep(z - z0));
+    let value = |dx: i32, dy: i32, dz: i32| {
+      let hash =
+        hash_noise_3d_u32(xi.wrapping_add(dx), yi.wrapping_add(dy), zi.wrapping_add(dz), self.seed);
+      fx_to_f32(lattice_value(hash))
+    };
+    let near =
+      lerp(lerp(value(0, 0, 0), value(1, 0, 0), u), lerp(value(0, 1, 0), value(1, 1, 0), u), v);
+    let far =
+      lerp(lerp(value(0, 0, 1), value(1, 0, 1), u), lerp(value(0, 1, 1), value(1, 1, 1), u), v);
+    lerp(near, far, w)
+  }
+
+  /// Samples 1D noise in 16.16 fixed-point.
+  #[inline]
+  pub fn sample_1d_fx(self, x: i32) -> i32 {
+    let (xi, xf) = (x >> 16, x & 0xFFFF);
+    let a = lattice_value(hash_noise_u32(xi, self.seed));
+    let b = lattice_value(hash_noise_u32(xi.wrapping_add(1), self.seed));
+    lerp_fx(a, b, smoothstep_fx(xf))
+  }
+
+  /// Samples 2D noise in 16.16 fixed-point.
+  #[inline]
+  pub fn sample_2d_fx(self, x: i32, y: i32) -> i32 {
+    let (xi, xf) = (x >> 16, x & 0xFFFF);
+    let (yi, yf) = (y >> 16, y & 0xFFFF);
+    let (u, v) = (smoothstep_fx(xf), smoothstep_fx(yf));
+    let value = |dx: i32, dy: i32| {
+      lattice_value(hash_noise_2d_u32(xi.wrapping_add(dx), yi.wrapping_add(dy), self.seed))
+    };
+    lerp_fx(lerp_fx(value(0, 0), value(1, 0), u), lerp_fx(value(0, 1), value(1, 1), u), v)
+  }
+
+  /// Fills a buffer with 2D noise, using only fixed-point math.
+  ///
+  /// * The buffer is rows of `width` pixels. Pixel `(px, py)` samples the noise
+  ///   at `origin + (px, py) * step`, with `origin` and `step` in 16.16
+  ///   fixed-point.
+  /// * Each row blends the lattice values vertically once per cell, and then
+  ///   only blends horizontally per pixel, so this is much faster than calling
+  ///   [`sample_2d_fx`](Self::sample_2d_fx) for each pixel. The values can
+  ///   differ from that by rounding.
+  /// * If the buffer isn't a whole number of rows, the last row is partial.
+  ///
+  /// ## Panics
+  /// * If `width` is 0.
+  #[inline]
+  pub fn fill_2d_fx<T: NoisePixel>(
+    self, buf: &mut [T], width: usize, origin: (i32, i32), step: i32,
+  ) {
+    assert!(width > 0, "ValueNoise::fill_2d_fx> The width must be more than 0.");
+    let value = |x: i32, y: i32| lattice_value(hash_noise_2d_u32(x, y, self.seed));
+    let mut y = origin.1;
+    for row in buf.chunks_mut(width) {
+      let (yi, v) = (y >> 16, smoothstep_fx(y & 0xFFFF));
+      // The vertically blended value of the lattice column at `xi`.
+      let column = |xi: i32| lerp_fx(value(xi, yi), value(xi, yi.wrapping_add(1)), v);
+      let mut x = origin.0;
+      let mut xi = x >> 16;
+      let (mut left, mut right) = (column(xi), column(xi.wrapping_add(1)));
+      for pixel in row.iter_mut() {
+        let cell = x >> 16;
+        if cell != xi {
+          if cell == xi.wrapping_add(1) {
+            left = right;
+          } else {
+            left = column(cell);
+          }
+          right = column(cell.wrapping_add(1));
+          xi = cell;
+        }
+        *pixel = T::from_noise_fx(lerp_fx(left, right, smoothstep_fx(x & 0xFFFF)));
+        x = x.wrapping_add(step);
+      }
+      y = y.wrapping_add(step);
+    }
+  }
+
+  /// Samples 3D noise in 16.16 fixed-point.
+  #[inline]
+  pub fn sample_3d_fx(self, x: i32, y: i32, z: i32) -> i32 {
+    let (xi, xf) = (x >> 16, x & 0xFFFF);
+    let (yi, yf) = (y >> 16, y & 0xFFFF);
+    let (zi, zf) = (z >> 16, z & 0xFFFF);
+    let (u, v, w) = (smoothstep_fx(xf), smoothstep_fx(yf), smoothstep_fx(zf));
+    let value = |dx: i32, dy: i32, dz: i32| {
+      lattice_value(hash_noise_3d_u32(
+        xi.wrapping_add(dx),
+        yi.wrapping_add(dy),
+        zi.wrapping_add(dz),
+        self.seed,
+      ))
+    };
+    let near = lerp_fx(
+      lerp_fx(value(0, 0, 0), value(1, 0, 0), u),
+      lerp_fx(value(0, 1, 0), value(1, 1, 0), u),
+      v,
+    );
+    let far = lerp_fx(
+      lerp_fx(value(0, 0, 1), value(1, 0, 1), u),
+      lerp_fx(value(0, 1, 1), value(1, 1, 1), u),
+      v,
+    );
+    lerp_fx(near, far, w)
+  }
+}
+
+/// Turns a hash into a 16.16 value in `-1.0 .. 1.0`.
+#[inline]
+fn lattice_value(hash: u32) -> i32 {
+  (hash >> 15) as i32 - FX_ONE
+}
+
+/// The cubic smoothstep curve, `3t^2 - 2t^3`.
+#[inline]
+fn smoothstep(t: f32) -> f32 {
+  t * t * (3.0 - 2.0 * t)
+}
+
+/// The cubic smoothstep curve in 16.16 fixed-point, for `t` in `0 ..= 1.0`.
+#[inline]
+fn smoothstep_fx(t: i32) -> i32 {
+  let t2 = (t * (t >> 1)) >> 15;
+  ((t2 as i64 * (3 * FX_ONE - 2 * t) as i64) >> 16) as i32
+}
+
+#[inline]
+fn fx_to_f32(x: i32) -> f32 {
+  x as f32 * (1.0 / FX_ONE as f32)
+}
@@ -0,0 +1,103 @@
+use super::*;
+
+/// The shape of the noise from a [`NoiseStream`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NoiseShape {
+  /// Gaussian noise, from the [`Normal`] distribution.
+  ///
+  /// This uses floats, so it's the slower choice on targets without an FPU.
+  Gaussian,
+  /// Gaussian noise, approximated with the central limit theorem: the sum of
+  /// four 16-bit uniform values from two `u32` draws.
+  ///
+  /// This is integer math only, so it's fast enough to make audio in real time
+  /// on small devices such as the GBA. The tails are cut off at about 3.46
+  /// standard deviations, which isn't heard.
+  GaussianClt,
+  /// Noise with a triangle shaped distribution (TPDF), from the sum of two
+  /// 16-bit uniform values from one `u32` draw.
+  ///
+  /// This is the usual noise for dithering audio. It's integer math only.
+  Triangle,
+}
+
+/// A source of white noise audio samples, as `i16` or `i8`.
+///
+/// * The noise has a mean of 0 and the [`NoiseShape`] picked.
+/// * The `level` is in `i16` units: the standard deviation for the Gaussian
+///   shapes, or the peak for [`Triangle`](NoiseShape::Triangle). Samples that
+///   go past the `i16` range are clamped.
+/// * `i8` samples (such as for the GBA's DirectSound channels) are the `i16`
+///   samples shifted down by 8 bits, so the level is still in `i16` units.
+/// * The random source is given to each call, so one stream config can feed any
+///   number of channels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NoiseStream {
+  #[cfg_attr(feature = "serde", serde(rename = "shape_v1"))]
+  shape: NoiseShape,
+  #[cfg_attr(feature = "serde", serde(rename = "level_v1"))]
+  level: u16,
+}
+
+impl NoiseStream {
+  /// Constructs a new `NoiseStream`.
+  #[inline]
+  pub const fn new(shape: NoiseShape, level: u16) -> Self {
+    Self { shape, level }
+  }
+
+  /// The shape of the noise.
+  #[inline]
+  pub const fn shape(&self) -> NoiseShape {
+    self.shape
+  }
+
+  /// The level of the noise, in `i16` units.
+  #[inline]
+  pub const fn level(&self) -> u16 {
+    self.level
+  }
+
+  /// Gives the next sample.
+  #[inline]
+  pub fn next_i16<G: Gen32 + ?Sized>(&self, gen: &mut G) -> i16 {
+    let level = i64::from(self.level);
+    let x: i64 = match self.shape {
+      NoiseShape::Gaussian => libm::round(standard_normal(gen) * level as f64) as i64,
+      NoiseShape::GaussianClt => {
+        let (a, b) = (gen.next_u32(), gen.next_u32());
+        let sum = (a >> 16) + (a & 0xFFFF) + (b >> 16) + (b & 0xFFFF);
+        let centered = i64::from(sum as i32 - 2 * 0xFFFF);
+        // The sum of 4 uniform `u16` values has a standard deviation of about
+        // `2^16 / sqrt(3)`, and this is `sqrt(3)` in 16.16 fixed point.
+        (centered * level * 113_512) >> 32
+      }
+      NoiseShape::Triangle => {
+        let r = gen.next_u32();
+        let centered = i64::from((r >> 16) as i32 + (r & 0xFFFF) as i32 - 0xFFFF);
+        (centered * level) >> 16
+      }
+    };
+    x.clamp(i64::from(i16::MIN), i64::from(i16::MAX)) as i16
+  }
+
+  /// Gives the next sample.
+  #[inline]
+  pub fn next_i8<G: Gen32 + ?Sized>(&self, gen: &mut G) -> i8 {
+    (self.next_i16(gen) >> 8) as i8
+  }
+
+  /// Fills the buffer with samples.
+  #[inline]
+  pub fn fill_i16<G: Gen32 + ?Sized>(&self, gen: &mut G, buf: &mut [i16]) {
+    buf.iter_mut().for_each(|x| *x = self.next_i16(gen));
+  }
+
+  /// Fills the buffer with samples.
+  #[inline]
+  pub fn fill_i8<G: Gen32 + ?Sized>(&self, gen: &mut G, buf: &mut [i8]) {
+    buf.iter_mut().for_each(|x| *x = self.next_i8(gen));
+  }
+}
@@ -0,0 +1,129 @@
+use super::*;
+
+const PCG_MULTIPLIER_128: u128 = 0x2360ED051FC65DA44385DF649FCCF645;
+
+/// Advances a PCG with 128 bits of state.
+macro_rules! pcg_core_state128 {
+  ($state:expr, $inc:expr) => {
+    $state.wrapping_mul(PCG_MULTIPLIER_128).wrapping_add($inc)
+  };
+}
+
+/// Permutation: XSL RR `u128` to `u64`.
+macro_rules! xsl_rr_u128_to_u64 {
+  ($state:expr) => {
+    ((($state >> 64) as u64) ^ ($state as u64)).rotate_right(($state >> 122) as u32)
+  };
+}
+
+make_jump_lcgX!(jump_lcg128, u128);
+
+/// A [permuted congruential
+/// generator](https://en.wikipedia.org/wiki/Permuted_congruential_generator)
+/// with 64 bits of output per step.
+///
+/// * This is the `pcg64` generator of the reference C library (128 bits of
+///   state, XSL RR output), and gives the same output for the same `seed`
+///   arguments.
+/// * Generally you should create new generator values with the
+///   [`seed`](Self::seed) constructor. The `inc` value selects the stream, so
+///   generators with the same `seed` but a different `inc` give unrelated
+///   sequences.
+/// * If you want to exactly save/restore a generator use the `Into` and `From`
+///   impls to convert the generator into and from a `[u128; 2]`.
+/// * On 32-bit targets the `u128` math is emulated and slow, so prefer
+///   [`Pcg32`] there.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pcg64 {
+  #[cfg_attr(feature = "serde", serde(rename = "state_v1"))]
+  state: u128,
+  #[cfg_attr(feature = "serde", serde(rename = "inc_v1"))]
+  inc: u128,
+}
+
+impl Pcg64 {
+  /// Seed a new generator.
+  pub const fn seed(seed: u128, inc: u128) -> Self {
+    let inc = (inc << 1) | 1;
+    let mut state = pcg_core_state128!(0_u128, inc);
+    state = state.wrapping_add(seed);
+    state = pcg_core_state128!(state, inc);
+    Self { state, inc }
+  }
+
+  /// The stream this generator is on.
+  ///
+  /// This is the `inc` value that was given to [`seed`](Self::seed) (or
+  /// [`set_stream`](Self::set_stream)), except that the top bit is always 0.
+  #[inline]
+  pub const fn stream(&self) -> u128 {
+    self.inc >> 1
+  }
+
+  /// Moves the generator to another stream, keeping the current state.
+  ///
+  /// Generators with the same state on different streams give unrelated
+  /// output, so one seeded generator can be split into many independent
+  /// sub-generators this way. The top bit of `inc` is ignored.
+  #[inline]
+  pub fn set_stream(&mut self, inc: u128) {
+    self.inc = (inc << 1) | 1;
+  }
+
+  /// Gives this generator moved to another stream, see
+  /// [`set_stream`](Self::set_stream).
+  #[inline]
+  #[must_use]
+  pub const fn with_stream(self, inc: u128) -> Self {
+    Self { state: self.state, inc: (inc << 1) | 1 }
+  }
+
+  /// Gets the next 64-bits of output.
+  #[inline]
+  pub fn next_u64(&mut self) -> u64 {
+    self.state = pcg_core_state128!(self.state, self.inc);
+    xsl_rr_u128_to_u64!(self.state)
+  }
+
+  /// Jumps the generator by `delta` steps forward.
+  #[inline]
+  pub fn jump(&mut self, delta: u64) {
+    self.state = jump_lcg128(delta as u128, self.state, PCG_MULTIPLIER_128, self.inc);
+  }
+}
+
+impl Default for Pcg64 {
+  fn default() -> Self {
+    const THE_DEFAULT: Pcg64 = Pcg64::seed(DEFAULT_PCG_SEED, DEFAULT_PCG_INC);
+    THE_DEFAULT
+  }
+}
+
+impl From<[u128; 2]> for Pcg64 {
+  fn from([state, inc]: [u128; 2]) -> Self {
+    Self { state, inc }
+  }
+}
+
+impl From<Pcg64> for [u128; 2] {
+  fn from(pcg: Pcg64) -> Self {
+    [pcg.state, pcg.inc]
+  }
+}
+
+impl Gen64 for Pcg64 {
+  fn next_u64(&mut self) -> u64 {
+    Pcg64::next_u64(self)
+  }
+}
+
+impl Gen32 for Pcg64 {
+  fn next_u32(&mut self) -> u32 {
+    (Pcg64::next_u64(self) >> 32) as u32
+  }
+
+  fn next_u64(&mut self) -> u64 {
+    Pcg64::next_u64(self)
+  }
+}
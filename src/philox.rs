@@ -0,0 +1,133 @@
+use super::*;
+
+const PHILOX_M4X32_0: u32 = 0xD2511F53;
+const PHILOX_M4X32_1: u32 = 0xCD9E8D57;
+const PHILOX_W32_0: u32 = 0x9E3779B9;
+const PHILOX_W32_1: u32 = 0xBB67AE85;
+
+/// Gives the high and low halves of the full product.
+#[must_use]
+#[inline(always)]
+const fn mulhilo32(a: u32, b: u32) -> (u32, u32) {
+  let p = (a as u64).wrapping_mul(b as u64);
+  ((p >> 32) as u32, p as u32)
+}
+
+/// A [Philox](https://www.thesalmons.org/john/random123/) 4x32-10
+/// counter-based generator, with 32 bits of output per step.
+///
+/// * A counter-based generator's output is a pure function of a key and a
+///   128-bit counter: see [`block`](Self::block). Each counter value gives four
+///   outputs, and stepping the generator just increments the counter.
+/// * This makes parallel or deterministic streams trivial. Give each entity or
+///   thread its own key (or its own range of counter values) and it gets an
+///   independent stream, with no need to jump or re-seed anything.
+/// * If you want to exactly save/restore a generator use [`key`](Self::key) and
+///   [`counter`](Self::counter), then call [`seed`](Self::seed) to restore. The
+///   counter is only advanced in whole blocks, so
+///   [`word_index`](Self::word_index) gives the position within the current
+///   block.
+/// * The methods on this type are quite minimal. You're expected to use the
+///   [`Gen32`] trait to provide most of the useful operations.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Philox4x32 {
+  #[cfg_attr(feature = "serde", serde(rename = "key_v1"))]
+  key: [u32; 2],
+  /// The counter of the next block to compute.
+  #[cfg_attr(feature = "serde", serde(rename = "counter_v1"))]
+  counter: u128,
+  #[cfg_attr(feature = "serde", serde(rename = "buffer_v1"))]
+  buffer: [u32; 4],
+  /// Index of the next unused word in `buffer`, 4 if it's all used.
+  #[cfg_attr(feature = "serde", serde(rename = "index_v1"))]
+  index: usize,
+}
+
+impl Philox4x32 {
+  /// Makes a generator that will next output the block for `counter`.
+  pub const fn seed(key: u64, counter: u128) -> Self {
+    Self { key: [key as u32, (key >> 32) as u32], counter, buffer: [0; 4], index: 4 }
+  }
+
+  /// Computes the four output words for a given key and counter.
+  ///
+  /// This is the whole generator: the other methods only keep track of which
+  /// counter comes next.
+  #[must_use]
+  pub const fn block(key: u64, counter: u128) -> [u32; 4] {
+    let mut k = [key as u32, (key >> 32) as u32];
+    let mut c =
+      [counter as u32, (counter >> 32) as u32, (counter >> 64) as u32, (counter >> 96) as u32];
+    let mut round = 0;
+    while round < 10 {
+      if round > 0 {
+        k[0] = k[0].wrapping_add(PHILOX_W32_0);
+        k[1] = k[1].wrapping_add(PHILOX_W32_1);
+      }
+      let (hi0, lo0) = mulhilo32(PHILOX_M4X32_0, c[0]);
+      let (hi1, lo1) = mulhilo32(PHILOX_M4X32_1, c[2]);
+      c = [hi1 ^ c[1] ^ k[0], lo1, hi0 ^ c[3] ^ k[1], lo0];
+      round += 1;
+    }
+    c
+  }
+
+  /// Gets the next 32-bits of output.
+  #[inline]
+  pub fn next_u32(&mut self) -> u32 {
+    if self.index >= 4 {
+      self.buffer = Self::block(self.key(), self.counter);
+      self.counter = self.counter.wrapping_add(1);
+      self.index = 0;
+    }
+    let out = self.buffer[self.index];
+    self.index += 1;
+    out
+  }
+
+  /// The key of this generator.
+  #[inline]
+  pub const fn key(&self) -> u64 {
+    (self.key[0] as u64) | ((self.key[1] as u64) << 32)
+  }
+
+  /// The counter of the current block.
+  ///
+  /// If no output has been taken from this block yet, this is the block that
+  /// the next output will come from.
+  #[inline]
+  pub const fn counter(&self) -> u128 {
+    if self.index >= 4 {
+      self.counter
+    } else {
+      self.counter.wrapping_sub(1)
+    }
+  }
+
+  /// How many words of the current block have been used, `0 .. 4`.
+  #[inline]
+  pub const fn word_index(&self) -> usize {
+    self.index % 4
+  }
+
+  /// Moves to the start of the block for a given counter.
+  #[inline]
+  pub fn set_counter(&mut self, counter: u128) {
+    self.counter = counter;
+    self.index = 4;
+  }
+}
+
+impl Default for Philox4x32 {
+  fn default() -> Self {
+    const THE_DEFAULT: Philox4x32 = Philox4x32::seed(DEFAULT_PCG_SEED as _, 0);
+    THE_DEFAULT
+  }
+}
+
+impl Gen32 for Philox4x32 {
+  fn next_u32(&mut self) -> u32 {
+    Philox4x32::next_u32(self)
+  }
+}
@@ -0,0 +1,71 @@
+use super::*;
+
+/// A pink noise generator, using the Voss–McCartney algorithm.
+///
+/// * Pink noise has equal power in each octave (the power falls off as
+///   `1/frequency`), so it sounds softer than white noise and is good for
+///   audio, or for slowly wandering values such as procedural modulation.
+/// * The output is the sum of `N` random rows plus one fresh white value. Row
+///   `k` is re-rolled every `2^(k+1)` samples, so each row covers about one
+///   octave. The default of 16 rows covers the whole audible range at normal
+///   sample rates.
+/// * The sum is averaged, so the output never clips. Because it's an average of
+///   many values it's usually well under full scale (the RMS level is about
+///   `0.58 / sqrt(N+1)` of full scale), so scale it up as needed.
+/// * The random source is given to each call, so the generator itself is just
+///   the rows and can be serialized with the `serde` feature.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PinkNoise<const N: usize = 16> {
+  #[cfg_attr(feature = "serde", serde(rename = "rows_v1", with = "crate::serde_array"))]
+  rows: [i16; N],
+  #[cfg_attr(feature = "serde", serde(rename = "counter_v1"))]
+  counter: u32,
+}
+
+impl<const N: usize> PinkNoise<N> {
+  /// Constructs a new generator, with random starting rows.
+  ///
+  /// ## Panics
+  /// * If `N` is 0 or more than 32.
+  #[inline]
+  pub fn new<G: Gen32 + ?Sized>(gen: &mut G) -> Self {
+    assert!(N > 0 && N <= 32, "PinkNoise::new> Row count must be 1 to 32.");
+    let mut rows = [0_i16; N];
+    rows.iter_mut().for_each(|r| *r = gen.next_u16() as i16);
+    Self { rows, counter: 0 }
+  }
+
+  /// Gives the next sample, using the full `i16` range.
+  #[inline]
+  pub fn next_i16<G: Gen32 + ?Sized>(&mut self, gen: &mut G) -> i16 {
+    let r = gen.next_u32();
+    self.counter = self.counter.wrapping_add(1);
+    let k = self.counter.trailing_zeros() as usize;
+    if k < N {
+      self.rows[k] = (r >> 16) as i16;
+    }
+    // With at most 32 rows this is cheap, and can't overflow.
+    let sum: i32 = self.rows.iter().map(|&r| i32::from(r)).sum();
+    let white = i32::from(r as i16);
+    ((sum + white) / (N as i32 + 1)) as i16
+  }
+
+  /// Gives the next sample, in `-1.0 .. 1.0`.
+  #[inline]
+  pub fn next_f32<G: Gen32 + ?Sized>(&mut self, gen: &mut G) -> f32 {
+    f32::from(self.next_i16(gen)) * (1.0 / 32768.0)
+  }
+
+  /// Fills the buffer with samples, using the full `i16` range.
+  #[inline]
+  pub fn fill_i16<G: Gen32 + ?Sized>(&mut self, gen: &mut G, buf: &mut [i16]) {
+    buf.iter_mut().for_each(|x| *x = self.next_i16(gen));
+  }
+
+  /// Fills the buffer with samples, in `-1.0 .. 1.0`.
+  #[inline]
+  pub fn fill_f32<G: Gen32 + ?Sized>(&mut self, gen: &mut G, buf: &mut [f32]) {
+    buf.iter_mut().for_each(|x| *x = self.next_f32(gen));
+  }
+}
@@ -0,0 +1,175 @@
+use super::*;
+
+/// The saved state of a [`PityTable`]: how many rolls since the pity outcome
+/// last came up.
+///
+/// * This is kept apart from the table so that one table can be shared (or be a
+///   `const`) while each player has their own counter.
+/// * With the `serde` feature this can be serialized, so the pity count can be
+///   saved along with the rest of the player's data.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PityCounter {
+  #[cfg_attr(feature = "serde", serde(rename = "misses_v1"))]
+  misses: u32,
+}
+
+impl PityCounter {
+  /// A counter with no misses.
+  #[inline]
+  pub const fn new() -> Self {
+    Self { misses: 0 }
+  }
+
+  /// The number of rolls since the pity outcome last came up.
+  #[inline]
+  pub const fn misses(&self) -> u32 {
+    self.misses
+  }
+
+  /// Sets the miss count back to 0.
+  #[inline]
+  pub fn reset(&mut self) {
+    self.misses = 0;
+  }
+}
+
+impl From<u32> for PityCounter {
+  fn from(misses: u32) -> Self {
+    Self { misses }
+  }
+}
+
+impl From<PityCounter> for u32 {
+  fn from(counter: PityCounter) -> Self {
+    counter.misses
+  }
+}
+
+/// A weighted table with "pity" rules for one of its outcomes, as used in
+/// gacha style games.
+///
+/// * Outcomes are indexes into the weights, picked with chance proportional to
+///   their weight.
+/// * One outcome is the *pity* outcome (usually the rarest). Each roll that
+///   isn't the pity outcome counts as a miss in the [`PityCounter`].
+/// * **Hard pity:** the roll after `hard_pity - 1` misses is always the pity
+///   outcome.
+/// * **Soft pity:** (optional, see [`with_soft_pity`](Self::with_soft_pity))
+///   once the miss count passes a starting point, the pity outcome's weight
+///   goes up by a fixed step for every further miss.
+/// * The weights can be an array or a slice (anything that's `AsRef<[u32]>`),
+///   so no allocation is needed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PityTable<B> {
+  weights: B,
+  total: u64,
+  pity_index: usize,
+  hard_pity: u32,
+  soft_start: u32,
+  soft_step: u32,
+}
+
+impl<B: AsRef<[u32]>> PityTable<B> {
+  /// Constructs a new table.
+  ///
+  /// ## Panics
+  /// * See [`try_new`](Self::try_new).
+  #[inline]
+  pub fn new(weights: B, pity_index: usize, hard_pity: u32) -> Self {
+    Self::try_new(weights, pity_index, hard_pity).expect("PityTable::new> Invalid parameters.")
+  }
+
+  /// Constructs a new table, or `None` on failure.
+  ///
+  /// * `hard_pity` is the roll number (counting from 1 after the last pity
+  ///   outcome) on which the pity outcome is guaranteed. Use `u32::MAX` to
+  ///   effectively have no hard pity.
+  ///
+  /// ## Failure
+  /// * If `pity_index` is not a valid index into the weights.
+  /// * If the weights add up to 0 (including if there are none).
+  /// * If `hard_pity` is 0.
+  #[inline]
+  pub fn try_new(weights: B, pity_index: usize, hard_pity: u32) -> Option<Self> {
+    let w = weights.as_ref();
+    let total: u64 = w.iter().map(|&x| x as u64).sum();
+    if pity_index >= w.len() || total == 0 || hard_pity == 0 {
+      return None;
+    }
+    Some(Self { weights, total, pity_index, hard_pity, soft_start: u32::MAX, soft_step: 0 })
+  }
+
+  /// Adds soft pity: after `start` misses in a row, each further miss adds
+  /// `step` to the pity outcome's weight.
+  #[inline]
+  pub fn with_soft_pity(self, start: u32, step: u32) -> Self {
+    Self { soft_start: start, soft_step: step, ..self }
+  }
+
+  /// The base weights.
+  #[inline]
+  pub fn weights(&self) -> &[u32] {
+    self.weights.as_ref()
+  }
+
+  /// The index of the pity outcome.
+  #[inline]
+  pub const fn pity_index(&self) -> usize {
+    self.pity_index
+  }
+
+  /// The pity outcome's weight on the next roll, given the counter.
+  ///
+  /// On a hard pity roll this gives `None`, since the outcome is certain.
+  #[inline]
+  pub fn pity_weight(&self, counter: &PityCounter) -> Option<u64> {
+    if counter.misses >= self.hard_pity - 1 {
+      return None;
+    }
+    let base = self.weights.as_ref()[self.pity_index] as u64;
+    let extra = (counter.misses.saturating_sub(self.soft_start) as u64) * (self.soft_step as u64);
+    Some(base.saturating_add(extra))
+  }
+
+  /// The chance of the pity outcome on the next roll, given the counter.
+  #[inline]
+  pub fn pity_chance(&self, counter: &PityCounter) -> f64 {
+    match self.pity_weight(counter) {
+      Some(w) => {
+        let base = self.weights.as_ref()[self.pity_index] as u64;
+        w as f64 / (self.total - base).saturating_add(w) as f64
+      }
+      None => 1.0,
+    }
+  }
+
+  /// Rolls an outcome, updating the counter.
+  #[inline]
+  pub fn roll<G: Gen32 + ?Sized>(&self, counter: &mut PityCounter, gen: &mut G) -> usize {
+    let out = match self.pity_weight(counter) {
+      None => self.pity_index,
+      Some(pity_w) => {
+        let weights = self.weights.as_ref();
+        let base = weights[self.pity_index] as u64;
+        let mut r = next_bounded_u64(gen, (self.total - base).saturating_add(pity_w));
+        let mut out = 0;
+        for (i, &w) in weights.iter().enumerate() {
+          let w = if i == self.pity_index { pity_w } else { w as u64 };
+          if r < w {
+            out = i;
+            break;
+          }
+          r -= w;
+        }
+        out
+      }
+    };
+    if out == self.pity_index {
+      counter.misses = 0;
+    } else {
+      counter.misses = counter.misses.saturating_add(1);
+    }
+    out
+  }
+}
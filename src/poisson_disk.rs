@@ -0,0 +1,178 @@
+use super::*;
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+/// Marks an empty grid cell.
+const EMPTY_CELL: u32 = u32::MAX;
+
+/// Places well spaced random 2D points with Bridson's Poisson-disk sampling.
+///
+/// * Points are placed in the rectangle `0.0 .. width` by `0.0 .. height`, and
+///   no two points are closer than `radius`. New points are tried around
+///   existing ones until no more will fit, so the result covers the area evenly
+///   without the clumps and gaps of uniform random points.
+/// * [`sample_into`](Self::sample_into) needs no allocation: you give it the
+///   output buffer and a scratch grid buffer (see
+///   [`grid_len`](Self::grid_len)). With the `std` feature,
+///   [`sample`](Self::sample) allocates these for you.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PoissonDisk {
+  width: f32,
+  height: f32,
+  radius: f32,
+  attempts: u32,
+}
+
+impl PoissonDisk {
+  /// Sets up a sampler, with 30 attempts per point.
+  ///
+  /// ## Panics
+  /// * See [`try_new`](Self::try_new).
+  #[inline]
+  pub fn new(width: f32, height: f32, radius: f32) -> Self {
+    Self::try_new(width, height, radius).expect("PoissonDisk::new> Invalid parameters.")
+  }
+
+  /// Sets up a sampler with 30 attempts per point, or `None` on failure.
+  ///
+  /// ## Failure
+  /// * If `width`, `height`, or `radius` aren't finite and positive.
+  /// * If the grid would need more than `u32::MAX` cells.
+  #[inline]
+  pub fn try_new(width: f32, height: f32, radius: f32) -> Option<Self> {
+    let ok = |x: f32| x.is_finite() && x > 0.0;
+    if !(ok(width) && ok(height) && ok(radius)) {
+      return None;
+    }
+    let out = Self { width, height, radius, attempts: 30 };
+    let (gw, gh) = out.grid_dims();
+    if (gw as u64) * (gh as u64) >= EMPTY_CELL as u64 {
+      return None;
+    }
+    Some(out)
+  }
+
+  /// Sets how many new points are tried around each point before giving up
+  /// on it. More attempts pack points more tightly, but take longer.
+  #[inline]
+  pub fn with_attempts(self, attempts: u32) -> Self {
+    Self { attempts, ..self }
+  }
+
+  /// The length of grid buffer that [`sample_into`](Self::sample_into) needs.
+  #[inline]
+  pub fn grid_len(&self) -> usize {
+    let (gw, gh) = self.grid_dims();
+    gw * gh
+  }
+
+  #[inline]
+  fn cell_size(&self) -> f32 {
+    self.radius * core::f32::consts::FRAC_1_SQRT_2
+  }
+
+  #[inline]
+  fn grid_dims(&self) -> (usize, usize) {
+    let cs = self.cell_size();
+    (
+      libm::ceilf(self.width / cs).max(1.0) as usize,
+      libm::ceilf(self.height / cs).max(1.0) as usize,
+    )
+  }
+
+  /// Places points into `points`, giving how many were placed.
+  ///
+  /// * This stops early if `points` fills up (the points placed so far are
+  ///   still well spaced, but won't cover the whole area).
+  /// * `grid` is used as scratch space, and its contents are overwritten.
+  ///
+  /// ## Panics
+  /// * If `grid` is shorter than [`grid_len`](Self::grid_len).
+  pub fn sample_into<G: Gen32 + ?Sized>(
+    &self, gen: &mut G, points: &mut [[f32; 2]], grid: &mut [u32],
+  ) -> usize {
+    let (gw, gh) = self.grid_dims();
+    assert!(grid.len() >= gw * gh, "PoissonDisk::sample_into> The grid buffer is too short.");
+    let grid = &mut grid[..gw * gh];
+    grid.iter_mut().for_each(|c| *c = EMPTY_CELL);
+    if points.is_empty() {
+      return 0;
+    }
+    let cs = self.cell_size();
+    let cell_of = |p: [f32; 2]| -> (usize, usize) {
+      (((p[0] / cs) as usize).min(gw - 1), ((p[1] / cs) as usize).min(gh - 1))
+    };
+    let r_sq = self.radius * self.radius;
+    let fits = |points: &[[f32; 2]], grid: &[u32], c: [f32; 2]| -> bool {
+      let (cx, cy) = cell_of(c);
+      for y in cy.saturating_sub(2)..(cy + 3).min(gh) {
+        for x in cx.saturating_sub(2)..(cx + 3).min(gw) {
+          let i = grid[y * gw + x];
+          if i != EMPTY_CELL {
+            let p = points[i as usize];
+            let (dx, dy) = (p[0] - c[0], p[1] - c[1]);
+            if dx * dx + dy * dy < r_sq {
+              return false;
+            }
+          }
+        }
+      }
+      true
+    };
+    let first = [gen.next_f32_unit() * self.width, gen.next_f32_unit() * self.height];
+    points[0] = first;
+    let (cx, cy) = cell_of(first);
+    grid[cy * gw + cx] = 0;
+    // Points before `done` are finished, and the rest are "active".
+    let (mut done, mut count) = (0, 1);
+    while done < count {
+      let i = done + next_bounded_u64(gen, (count - done) as u64) as usize;
+      let p = points[i];
+      let mut placed = false;
+      for _ in 0..self.attempts {
+        let (dx, dy) = gen.unit_circle_point();
+        // Uniform by area in the ring from `radius` to `2 * radius`.
+        let dist = self.radius * libm::sqrtf(1.0 + 3.0 * gen.next_f32_unit());
+        let c = [p[0] + dx * dist, p[1] + dy * dist];
+        let in_bounds = c[0] >= 0.0 && c[0] < self.width && c[1] >= 0.0 && c[1] < self.height;
+        if in_bounds && fits(points, grid, c) {
+          if count == points.len() {
+            return count;
+          }
+          points[count] = c;
+          let (cx, cy) = cell_of(c);
+          grid[cy * gw + cx] = count as u32;
+          count += 1;
+          placed = true;
+          break;
+        }
+      }
+      if !placed {
+        // Swap the finished point to the end of the done section, keeping the
+        // grid's indexes in step.
+        points.swap(i, done);
+        for &j in &[i, done] {
+          let (cx, cy) = cell_of(points[j]);
+          grid[cy * gw + cx] = j as u32;
+        }
+        done += 1;
+      }
+    }
+    count
+  }
+
+  /// Places points until no more will fit.
+  #[cfg(feature = "std")]
+  pub fn sample<G: Gen32 + ?Sized>(&self, gen: &mut G) -> Vec<[f32; 2]> {
+    // Each point's disk of radius `radius / 2` doesn't overlap any other, and
+    // stays within a margin of `radius / 2` around the area.
+    let half = self.radius * 0.5;
+    let area = (self.width + self.radius) * (self.height + self.radius);
+    let max_points = (area / (core::f32::consts::PI * half * half)) as usize + 1;
+    let mut points = std::vec![[0.0; 2]; max_points];
+    let mut grid = std::vec![0; self.grid_len()];
+    let n = self.sample_into(gen, &mut points, &mut grid);
+    points.truncate(n);
+    points
+  }
+}
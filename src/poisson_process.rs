@@ -0,0 +1,93 @@
+use super::*;
+
+/// Random events at a constant average rate, one after another.
+///
+/// * The gap between events follows the [`Exponential`] distribution with
+///   `lambda` equal to the rate, so the average gap is `1.0 / rate`.
+/// * This keeps the total time so far, so gaps can be given either as exact
+///   amounts of time or as whole ticks of a fixed time step (such as game
+///   frames, with the rate given per frame). Tick gaps are worked out from the
+///   total time, so rounding never builds up: over a long run the number of
+///   events per tick is still the rate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PoissonProcess {
+  #[cfg_attr(feature = "serde", serde(rename = "rate_v1"))]
+  rate: f64,
+  #[cfg_attr(feature = "serde", serde(rename = "time_v1"))]
+  time: f64,
+}
+
+impl PoissonProcess {
+  /// Constructs a new `PoissonProcess`, starting at time 0.
+  ///
+  /// ## Panics
+  /// * If `rate` isn't finite, or isn't positive.
+  #[inline]
+  pub fn new(rate: f64) -> Self {
+    Self::try_new(rate).expect("PoissonProcess::new> Invalid parameters.")
+  }
+
+  /// Constructs a new `PoissonProcess` starting at time 0, or `None` on
+  /// failure.
+  ///
+  /// ## Failure
+  /// * If `rate` isn't finite, or isn't positive.
+  #[inline]
+  pub fn try_new(rate: f64) -> Option<Self> {
+    if rate.is_finite() && rate > 0.0 {
+      Some(Self { rate, time: 0.0 })
+    } else {
+      None
+    }
+  }
+
+  /// The average number of events per unit of time.
+  #[inline]
+  pub const fn rate(&self) -> f64 {
+    self.rate
+  }
+
+  /// The time of the latest event.
+  #[inline]
+  pub const fn time(&self) -> f64 {
+    self.time
+  }
+
+  /// Sets the time back to 0.
+  #[inline]
+  pub fn reset(&mut self) {
+    self.time = 0.0;
+  }
+
+  /// Gives the time until the next event, and moves to it.
+  #[inline]
+  pub fn next_gap<G: Gen32 + ?Sized>(&mut self, gen: &mut G) -> f64 {
+    let gap = standard_exp(gen) / self.rate;
+    self.time += gap;
+    gap
+  }
+
+  /// Gives the time until the next event, and moves to it, using only `f32`
+  /// math for the gap.
+  ///
+  /// See [`Exponential::sample_f32`] for the limits of this.
+  #[inline]
+  pub fn next_gap_f32<G: Gen32 + ?Sized>(&mut self, gen: &mut G) -> f32 {
+    // `1.0 - unit` is in `0.0 < x <= 1.0`, so the log is always finite.
+    let gap = -libm::logf(1.0 - gen.next_f32_unit()) / (self.rate as f32);
+    self.time += gap as f64;
+    gap
+  }
+
+  /// Gives the number of whole ticks until the next event, and moves to it.
+  ///
+  /// * This is 0 when the next event falls in the same tick as the last one,
+  ///   which happens often at rates near (or over) 1 event per tick.
+  #[inline]
+  pub fn next_gap_ticks<G: Gen32 + ?Sized>(&mut self, gen: &mut G) -> u64 {
+    let before = self.time as u64;
+    self.next_gap(gen);
+    (self.time as u64).saturating_sub(before)
+  }
+}
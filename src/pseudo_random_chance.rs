@@ -0,0 +1,170 @@
+use super::*;
+
+/// A chance of success using a "pseudo-random distribution" (PRD), as in many
+/// games (most famously Dota).
+///
+/// * The chance of success on the `n`th attempt since the last success is `c *
+///   n`, so the chance ramps up with each miss and resets on a success. This
+///   makes long streaks of misses (and of hits) far less common than with
+///   independent rolls.
+/// * [`new`](Self::new) takes the long-run success rate you want, and finds the
+///   `c` value that gives that rate. If you already know `c` use
+///   [`from_c`](Self::from_c) instead.
+/// * The miss count is part of the value, so each thing that rolls (each unit's
+///   crit chance, each chest, etc) should have its own.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PseudoRandomChance {
+  #[cfg_attr(feature = "serde", serde(rename = "c_v1"))]
+  c: f64,
+  #[cfg_attr(feature = "serde", serde(rename = "misses_v1"))]
+  misses: u32,
+}
+
+impl PseudoRandomChance {
+  /// Makes a PRD chance with the given long-run success rate.
+  ///
+  /// ## Panics
+  /// * If `p` is not in `0.0 ..= 1.0`.
+  #[inline]
+  pub fn new(p: f64) -> Self {
+    match Self::try_new(p) {
+      Some(prd) => prd,
+      None => panic!("PseudoRandomChance::new> The probability must be in 0.0 ..= 1.0."),
+    }
+  }
+
+  /// Makes a PRD chance with the given long-run success rate.
+  ///
+  /// * The `c` value is found with a binary search, and the work it takes grows
+  ///   as `c` shrinks (about `600 / sqrt(c)` steps), so avoid doing this for
+  ///   tiny rates in a hot loop. Build the value once and `clone` it (or save
+  ///   [`c`](Self::c)) instead.
+  ///
+  /// ## Failure
+  /// * If `p` is not in `0.0 ..= 1.0`.
+  #[inline]
+  pub fn try_new(p: f64) -> Option<Self> {
+    if !(0.0..=1.0).contains(&p) {
+      return None;
+    }
+    // For small `p`, `c` is a little under `pi * p^2 / 2`, so this bound is
+    // well above it while narrowing the search a lot.
+    let (mut lo, mut hi) = (0.0, p.min(core::f64::consts::PI * p * p));
+    for _ in 0..64 {
+      let mid = (lo + hi) * 0.5;
+      if success_rate_of_c(mid) < p {
+        lo = mid;
+      } else {
+        hi = mid;
+      }
+    }
+    Some(Self { c: hi, misses: 0 })
+  }
+
+  /// Makes a PRD chance directly from its `c` value.
+  ///
+  /// ## Panics
+  /// * If `c` is not in `0.0 ..= 1.0`.
+  #[inline]
+  pub fn from_c(c: f64) -> Self {
+    assert!(
+      (0.0..=1.0).contains(&c),
+      "PseudoRandomChance::from_c> The constant must be in 0.0 ..= 1.0."
+    );
+    Self { c, misses: 0 }
+  }
+
+  /// The amount the chance goes up by with each miss.
+  #[inline]
+  pub const fn c(&self) -> f64 {
+    self.c
+  }
+
+  /// The long-run success rate.
+  ///
+  /// This is computed from `c`, so it can be slightly off from the value
+  /// given to [`new`](Self::new).
+  #[inline]
+  pub fn p(&self) -> f64 {
+    success_rate_of_c(self.c)
+  }
+
+  /// The number of misses since the last success.
+  #[inline]
+  pub const fn misses(&self) -> u32 {
+    self.misses
+  }
+
+  /// The chance that the next attempt is a success.
+  #[inline]
+  pub fn current_chance(&self) -> f64 {
+    (self.c * (self.misses as f64 + 1.0)).min(1.0)
+  }
+
+  /// Forgets all misses, as if there had just been a success.
+  #[inline]
+  pub fn reset(&mut self) {
+    self.misses = 0;
+  }
+
+  /// Makes an attempt, updating the miss count.
+  #[inline]
+  pub fn attempt<G: Gen32 + ?Sized>(&mut self, gen: &mut G) -> bool {
+    if gen.next_f64_unit() < self.current_chance() {
+      self.misses = 0;
+      true
+    } else {
+      self.misses = self.misses.saturating_add(1);
+      false
+    }
+  }
+}
+
+/// The long-run success rate of a PRD with the given `c`.
+///
+/// This is one over the expected number of attempts per success.
+fn success_rate_of_c(c: f64) -> f64 {
+  if c <= 0.0 {
+    return 0.0;
+  }
+  let mut expected = 0.0;
+  // Chance that every attempt so far has missed.
+  let mut all_missed = 1.0;
+  let mut n = 1.0;
+  loop {
+    let chance = (c * n).min(1.0);
+    expected += n * chance * all_missed;
+    all_missed *= 1.0 - chance;
+    // Once every attempt missing is this unlikely, the rest of the terms
+    // don't change the result. Waiting for exactly 0 would take `1 / c` steps,
+    // since a subnormal `all_missed` can stop shrinking.
+    if chance >= 1.0 || all_missed < 1e-18 {
+      break;
+    }
+    n += 1.0;
+  }
+  1.0 / expected
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn known_c_values() {
+    // Values from the Dota 2 wiki's PRD table.
+    let table =
+      [(0.05, 0.003801658303553139), (0.25, 0.08474409185231699), (0.5, 0.302103025348742)];
+    for &(p, c) in table.iter() {
+      let prd = PseudoRandomChance::new(p);
+      assert!((prd.c() - c).abs() < 1e-9, "p: {}, c: {}", p, prd.c());
+    }
+  }
+
+  #[test]
+  fn tiny_p_finishes() {
+    let prd = PseudoRandomChance::new(1e-5);
+    assert!((prd.p() - 1e-5).abs() < 1e-9);
+  }
+}
@@ -0,0 +1,432 @@
+use super::*;
+use rand_core::{RngCore, SeedableRng};
+
+/// Implements `RngCore` for generator types that implement [`Gen32`].
+macro_rules! impl_rng_core_via_gen32 {
+  ($($t:ty),* $(,)?) => {
+    $(
+      impl RngCore for $t {
+        #[inline]
+        fn next_u32(&mut self) -> u32 {
+          Gen32::next_u32(self)
+        }
+        #[inline]
+        fn next_u64(&mut self) -> u64 {
+          Gen32::next_u64(self)
+        }
+        #[inline]
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+          Gen32::fill_bytes(self, dst)
+        }
+      }
+    )*
+  };
+}
+
+/// Implements `RngCore` for generator types that implement [`Gen64`].
+macro_rules! impl_rng_core_via_gen64 {
+  ($($t:ty),* $(,)?) => {
+    $(
+      impl RngCore for $t {
+        #[inline]
+        fn next_u32(&mut self) -> u32 {
+          Gen64::next_u32(self)
+        }
+        #[inline]
+        fn next_u64(&mut self) -> u64 {
+          Gen64::next_u64(self)
+        }
+        #[inline]
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+          rand_core::impls::fill_bytes_via_next(self, dst)
+        }
+      }
+    )*
+  };
+}
+
+impl_rng_core_via_gen32!(
+  Pcg32,
+  RNG,
+  Xoshiro128pp,
+  Sfc32,
+  Jsf32,
+  ChaCha8,
+  ChaCha20Rng,
+  RomuTrio32,
+  Mt19937,
+  Isaac32,
+  Philox4x32,
+  Threefry4x32,
+  Msws,
+  XorShift128,
+  Mwc32,
+  Well512,
+  TinyMt32
+);
+
+impl_rng_core_via_gen64!(
+  Pcg64,
+  Xoshiro256pp,
+  SplitMix64,
+  WyRand,
+  RomuTrio,
+  RomuQuad,
+  Lehmer128,
+  Threefry2x64
+);
+
+/// The seed is the `seed` and `inc` values (little-endian), in that order.
+impl SeedableRng for Pcg32 {
+  type Seed = [u8; 16];
+
+  #[inline]
+  fn from_seed(seed: Self::Seed) -> Self {
+    let [s0, s1, s2, s3, s4, s5, s6, s7, i0, i1, i2, i3, i4, i5, i6, i7] = seed;
+    Pcg32::seed(
+      u64::from_le_bytes([s0, s1, s2, s3, s4, s5, s6, s7]),
+      u64::from_le_bytes([i0, i1, i2, i3, i4, i5, i6, i7]),
+    )
+  }
+}
+
+/// The seed is the `seed` and `inc` values (little-endian), in that order.
+impl SeedableRng for RNG {
+  type Seed = [u8; 8];
+
+  #[inline]
+  fn from_seed(seed: Self::Seed) -> Self {
+    let [s0, s1, s2, s3, i0, i1, i2, i3] = seed;
+    RNG::seed(u32::from_le_bytes([s0, s1, s2, s3]), u32::from_le_bytes([i0, i1, i2, i3]))
+  }
+}
+
+/// The seed is the four state words (little-endian), in order.
+impl SeedableRng for Xoshiro128pp {
+  type Seed = [u8; 16];
+
+  #[inline]
+  fn from_seed(seed: Self::Seed) -> Self {
+    let mut words = [0_u32; 4];
+    for (w, chunk) in words.iter_mut().zip(seed.chunks_exact(4)) {
+      let mut bytes = [0_u8; 4];
+      bytes.copy_from_slice(chunk);
+      *w = u32::from_le_bytes(bytes);
+    }
+    Xoshiro128pp::seed(words)
+  }
+}
+
+/// The seed is the four state words (little-endian), in order.
+impl SeedableRng for Xoshiro256pp {
+  type Seed = [u8; 32];
+
+  #[inline]
+  fn from_seed(seed: Self::Seed) -> Self {
+    let mut words = [0_u64; 4];
+    for (w, chunk) in words.iter_mut().zip(seed.chunks_exact(8)) {
+      let mut bytes = [0_u8; 8];
+      bytes.copy_from_slice(chunk);
+      *w = u64::from_le_bytes(bytes);
+    }
+    Xoshiro256pp::seed(words)
+  }
+}
+
+/// The seed is the state (little-endian).
+impl SeedableRng for SplitMix64 {
+  type Seed = [u8; 8];
+
+  #[inline]
+  fn from_seed(seed: Self::Seed) -> Self {
+    SplitMix64::seed(u64::from_le_bytes(seed))
+  }
+}
+
+/// The seed is the state (little-endian).
+impl SeedableRng for WyRand {
+  type Seed = [u8; 8];
+
+  #[inline]
+  fn from_seed(seed: Self::Seed) -> Self {
+    WyRand::seed(u64::from_le_bytes(seed))
+  }
+}
+
+/// The seed is the `a`, `b`, and `c` values (little-endian), in that order.
+impl SeedableRng for Sfc32 {
+  type Seed = [u8; 12];
+
+  #[inline]
+  fn from_seed(seed: Self::Seed) -> Self {
+    let [a0, a1, a2, a3, b0, b1, b2, b3, c0, c1, c2, c3] = seed;
+    Sfc32::seed(
+      u32::from_le_bytes([a0, a1, a2, a3]),
+      u32::from_le_bytes([b0, b1, b2, b3]),
+      u32::from_le_bytes([c0, c1, c2, c3]),
+    )
+  }
+}
+
+/// The seed is the `seed` value (little-endian).
+impl SeedableRng for Jsf32 {
+  type Seed = [u8; 4];
+
+  #[inline]
+  fn from_seed(seed: Self::Seed) -> Self {
+    Jsf32::seed(u32::from_le_bytes(seed))
+  }
+}
+
+/// The seed is the key (little-endian), and the stream is 0.
+impl SeedableRng for ChaCha8 {
+  type Seed = [u8; 32];
+
+  #[inline]
+  fn from_seed(seed: Self::Seed) -> Self {
+    let mut key = [0_u32; 8];
+    for (k, chunk) in key.iter_mut().zip(seed.chunks_exact(4)) {
+      let mut bytes = [0_u8; 4];
+      bytes.copy_from_slice(chunk);
+      *k = u32::from_le_bytes(bytes);
+    }
+    ChaCha8::seed(key, 0)
+  }
+}
+
+/// The seed is the key (little-endian), and the stream is 0.
+impl SeedableRng for ChaCha20Rng {
+  type Seed = [u8; 32];
+
+  #[inline]
+  fn from_seed(seed: Self::Seed) -> Self {
+    let mut key = [0_u32; 8];
+    for (k, chunk) in key.iter_mut().zip(seed.chunks_exact(4)) {
+      let mut bytes = [0_u8; 4];
+      bytes.copy_from_slice(chunk);
+      *k = u32::from_le_bytes(bytes);
+    }
+    ChaCha20Rng::seed(key, 0)
+  }
+}
+
+/// The seed is the `seed` value (little-endian).
+impl SeedableRng for RomuTrio {
+  type Seed = [u8; 8];
+
+  #[inline]
+  fn from_seed(seed: Self::Seed) -> Self {
+    RomuTrio::seed(u64::from_le_bytes(seed))
+  }
+}
+
+/// The seed is the `seed` value (little-endian).
+impl SeedableRng for RomuQuad {
+  type Seed = [u8; 8];
+
+  #[inline]
+  fn from_seed(seed: Self::Seed) -> Self {
+    RomuQuad::seed(u64::from_le_bytes(seed))
+  }
+}
+
+/// The seed is the `seed` value (little-endian).
+impl SeedableRng for RomuTrio32 {
+  type Seed = [u8; 8];
+
+  #[inline]
+  fn from_seed(seed: Self::Seed) -> Self {
+    RomuTrio32::seed(u64::from_le_bytes(seed))
+  }
+}
+
+/// The seed is the state (little-endian).
+impl SeedableRng for Lehmer128 {
+  type Seed = [u8; 16];
+
+  #[inline]
+  fn from_seed(seed: Self::Seed) -> Self {
+    Lehmer128::seed(u128::from_le_bytes(seed))
+  }
+}
+
+/// The seed is a single `u32` (little-endian), as with
+/// [`Mt19937::seed_u32`].
+impl SeedableRng for Mt19937 {
+  type Seed = [u8; 4];
+
+  #[inline]
+  fn from_seed(seed: Self::Seed) -> Self {
+    Mt19937::seed_u32(u32::from_le_bytes(seed))
+  }
+}
+
+/// The seed is the first 8 words of seed (little-endian), and the rest are
+/// zero, as with [`Isaac32::seed_from_slice`].
+impl SeedableRng for Isaac32 {
+  type Seed = [u8; 32];
+
+  #[inline]
+  fn from_seed(seed: Self::Seed) -> Self {
+    let mut words = [0_u32; 8];
+    for (w, chunk) in words.iter_mut().zip(seed.chunks_exact(4)) {
+      let mut bytes = [0_u8; 4];
+      bytes.copy_from_slice(chunk);
+      *w = u32::from_le_bytes(bytes);
+    }
+    Isaac32::seed_from_slice(&words)
+  }
+}
+
+/// The seed is the key (little-endian), and the counter starts at 0.
+impl SeedableRng for Philox4x32 {
+  type Seed = [u8; 8];
+
+  #[inline]
+  fn from_seed(seed: Self::Seed) -> Self {
+    Philox4x32::seed(u64::from_le_bytes(seed), 0)
+  }
+}
+
+/// The seed is the key (little-endian), and the counter starts at 0.
+impl SeedableRng for Threefry2x64 {
+  type Seed = [u8; 16];
+
+  #[inline]
+  fn from_seed(seed: Self::Seed) -> Self {
+    Threefry2x64::seed(u128::from_le_bytes(seed), 0)
+  }
+}
+
+/// The seed is the key (little-endian), and the counter starts at 0.
+impl SeedableRng for Threefry4x32 {
+  type Seed = [u8; 16];
+
+  #[inline]
+  fn from_seed(seed: Self::Seed) -> Self {
+    Threefry4x32::seed(u128::from_le_bytes(seed), 0)
+  }
+}
+
+/// The seed is the `seed` and `stream` values (little-endian), in that order.
+impl SeedableRng for Msws {
+  type Seed = [u8; 16];
+
+  #[inline]
+  fn from_seed(seed: Self::Seed) -> Self {
+    let [s0, s1, s2, s3, s4, s5, s6, s7, t0, t1, t2, t3, t4, t5, t6, t7] = seed;
+    Msws::seed(
+      u64::from_le_bytes([s0, s1, s2, s3, s4, s5, s6, s7]),
+      u64::from_le_bytes([t0, t1, t2, t3, t4, t5, t6, t7]),
+    )
+  }
+}
+
+/// The seed is the four state words (little-endian), in order.
+impl SeedableRng for XorShift128 {
+  type Seed = [u8; 16];
+
+  #[inline]
+  fn from_seed(seed: Self::Seed) -> Self {
+    let mut words = [0_u32; 4];
+    for (w, chunk) in words.iter_mut().zip(seed.chunks_exact(4)) {
+      let mut bytes = [0_u8; 4];
+      bytes.copy_from_slice(chunk);
+      *w = u32::from_le_bytes(bytes);
+    }
+    XorShift128::seed(words)
+  }
+}
+
+/// The seed is the `seed` and `carry` values (little-endian), in that order.
+impl SeedableRng for Mwc32 {
+  type Seed = [u8; 8];
+
+  #[inline]
+  fn from_seed(seed: Self::Seed) -> Self {
+    let [s0, s1, s2, s3, c0, c1, c2, c3] = seed;
+    Mwc32::seed(u32::from_le_bytes([s0, s1, s2, s3]), u32::from_le_bytes([c0, c1, c2, c3]))
+  }
+}
+
+impl<const R: usize> RngCore for Cmwc<R> {
+  #[inline]
+  fn next_u32(&mut self) -> u32 {
+    Gen32::next_u32(self)
+  }
+  #[inline]
+  fn next_u64(&mut self) -> u64 {
+    Gen32::next_u64(self)
+  }
+  #[inline]
+  fn fill_bytes(&mut self, dst: &mut [u8]) {
+    Gen32::fill_bytes(self, dst)
+  }
+}
+
+/// The seed is the `seed` value (little-endian).
+impl SeedableRng for Cmwc<4096> {
+  type Seed = [u8; 8];
+
+  #[inline]
+  fn from_seed(seed: Self::Seed) -> Self {
+    Cmwc::<4096>::seed(u64::from_le_bytes(seed))
+  }
+}
+
+/// The seed is the `seed` value (little-endian).
+impl SeedableRng for Well512 {
+  type Seed = [u8; 8];
+
+  #[inline]
+  fn from_seed(seed: Self::Seed) -> Self {
+    Well512::seed(u64::from_le_bytes(seed))
+  }
+}
+
+/// The seed is the `seed` value (little-endian), with the standard parameter
+/// set.
+impl SeedableRng for TinyMt32 {
+  type Seed = [u8; 4];
+
+  #[inline]
+  fn from_seed(seed: Self::Seed) -> Self {
+    TinyMt32::seed(u32::from_le_bytes(seed))
+  }
+}
+
+/// The seed is the `seed` and `inc` values (little-endian), in that order.
+impl SeedableRng for Pcg64 {
+  type Seed = [u8; 32];
+
+  #[inline]
+  fn from_seed(seed: Self::Seed) -> Self {
+    let mut s = [0_u8; 16];
+    let mut i = [0_u8; 16];
+    s.copy_from_slice(&seed[..16]);
+    i.copy_from_slice(&seed[16..]);
+    Pcg64::seed(u128::from_le_bytes(s), u128::from_le_bytes(i))
+  }
+}
+
+/// Lets any `RngCore` be used as a [`Gen32`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct RandCoreGen<R>(pub R);
+
+impl<R: RngCore> Gen32 for RandCoreGen<R> {
+  #[inline]
+  fn next_u32(&mut self) -> u32 {
+    self.0.next_u32()
+  }
+
+  #[inline]
+  fn next_u64(&mut self) -> u64 {
+    self.0.next_u64()
+  }
+
+  #[inline]
+  fn fill_bytes(&mut self, buf: &mut [u8]) {
+    self.0.fill_bytes(buf)
+  }
+}
@@ -0,0 +1,271 @@
+use super::*;
+
+/// A kind of step that a [`RandomWalk1D`] or [`RandomWalk2D`] can take.
+pub trait WalkStep {
+  /// The type of a position along one axis.
+  type Coord: Copy + PartialOrd;
+
+  /// Picks a step along a single axis.
+  fn step_1d<G: Gen32 + ?Sized>(&self, gen: &mut G) -> Self::Coord;
+
+  /// Picks a step in two dimensions.
+  fn step_2d<G: Gen32 + ?Sized>(&self, gen: &mut G) -> [Self::Coord; 2];
+
+  /// Moves `pos` by `step`, keeping the result within `bounds` (if any).
+  fn advance(
+    pos: Self::Coord, step: Self::Coord, bounds: Option<WalkBounds<Self::Coord>>,
+  ) -> Self::Coord;
+}
+
+/// Steps of one whole unit, for walking on a grid.
+///
+/// * In 1D this steps by `-1` or `+1`.
+/// * In 2D this steps to one of the 4 neighbors (no diagonals).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct LatticeStep;
+
+impl WalkStep for LatticeStep {
+  type Coord = i32;
+
+  #[inline]
+  fn step_1d<G: Gen32 + ?Sized>(&self, gen: &mut G) -> i32 {
+    if gen.next_bool() {
+      1
+    } else {
+      -1
+    }
+  }
+
+  #[inline]
+  fn step_2d<G: Gen32 + ?Sized>(&self, gen: &mut G) -> [i32; 2] {
+    match gen.next_u32() >> 30 {
+      0 => [1, 0],
+      1 => [-1, 0],
+      2 => [0, 1],
+      _ => [0, -1],
+    }
+  }
+
+  #[inline]
+  fn advance(pos: i32, step: i32, bounds: Option<WalkBounds<i32>>) -> i32 {
+    let p = i64::from(pos) + i64::from(step);
+    match bounds {
+      None => p as i32,
+      Some(WalkBounds::Clamp(lo, hi)) => p.clamp(i64::from(lo), i64::from(hi)) as i32,
+      Some(WalkBounds::Reflect(lo, hi)) => {
+        let (lo, width) = (i64::from(lo), i64::from(hi) - i64::from(lo));
+        if width == 0 {
+          return lo as i32;
+        }
+        let m = (p - lo).rem_euclid(2 * width);
+        (lo + if m > width { 2 * width - m } else { m }) as i32
+      }
+    }
+  }
+}
+
+/// Steps along each axis taken from a [`Normal`] distribution with a mean of 0.
+///
+/// * In 2D the two axes are stepped separately, so the walk has no favored
+///   direction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GaussianStep {
+  sigma: f64,
+}
+
+impl GaussianStep {
+  /// Constructs a new `GaussianStep`.
+  ///
+  /// ## Panics
+  /// * If `sigma` isn't finite, or is negative.
+  #[inline]
+  pub fn new(sigma: f64) -> Self {
+    Self::try_new(sigma).expect("GaussianStep::new> Invalid parameters.")
+  }
+
+  /// Constructs a new `GaussianStep`, or `None` on failure.
+  ///
+  /// ## Failure
+  /// * If `sigma` isn't finite, or is negative.
+  #[inline]
+  pub fn try_new(sigma: f64) -> Option<Self> {
+    if sigma.is_finite() && sigma >= 0.0 {
+      Some(Self { sigma })
+    } else {
+      None
+    }
+  }
+
+  /// The standard deviation of each step.
+  #[inline]
+  pub const fn sigma(&self) -> f64 {
+    self.sigma
+  }
+}
+
+impl WalkStep for GaussianStep {
+  type Coord = f64;
+
+  #[inline]
+  fn step_1d<G: Gen32 + ?Sized>(&self, gen: &mut G) -> f64 {
+    standard_normal(gen) * self.sigma
+  }
+
+  #[inline]
+  fn step_2d<G: Gen32 + ?Sized>(&self, gen: &mut G) -> [f64; 2] {
+    [standard_normal(gen) * self.sigma, standard_normal(gen) * self.sigma]
+  }
+
+  #[inline]
+  fn advance(pos: f64, step: f64, bounds: Option<WalkBounds<f64>>) -> f64 {
+    let p = pos + step;
+    match bounds {
+      None => p,
+      Some(WalkBounds::Clamp(lo, hi)) => p.max(lo).min(hi),
+      Some(WalkBounds::Reflect(lo, hi)) => {
+        let width = hi - lo;
+        if width == 0.0 {
+          return lo;
+        }
+        let m = libm::fmod(p - lo, 2.0 * width);
+        let m = if m < 0.0 { m + 2.0 * width } else { m };
+        lo + if m > width { 2.0 * width - m } else { m }
+      }
+    }
+  }
+}
+
+/// How a random walk stays within its bounds, as `(low, high)` inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WalkBounds<T> {
+  /// Steps past an edge stop at the edge.
+  Clamp(T, T),
+  /// Steps past an edge bounce back by the amount they went over.
+  Reflect(T, T),
+}
+
+impl<T: Copy + PartialOrd> WalkBounds<T> {
+  /// If the low bound is no more than the high bound.
+  #[inline]
+  fn is_valid(&self) -> bool {
+    let (Self::Clamp(lo, hi) | Self::Reflect(lo, hi)) = *self;
+    lo <= hi
+  }
+}
+
+/// A random walk along a line.
+///
+/// * Each call to `next` takes one step (picked by `S`) and gives the new
+///   position. The walk never ends, so use [`take`](Iterator::take) or similar.
+/// * Use [`LatticeStep`] for whole unit steps, or [`GaussianStep`] for
+///   continuous steps (such as a price-like series).
+/// * Optionally the walk can be kept within bounds. If the walk starts outside
+///   the bounds it's brought inside on the first step.
+#[derive(Debug)]
+pub struct RandomWalk1D<'g, G: Gen32 + ?Sized, S: WalkStep> {
+  gen: &'g mut G,
+  step: S,
+  pos: S::Coord,
+  bounds: Option<WalkBounds<S::Coord>>,
+}
+
+impl<'g, G: Gen32 + ?Sized, S: WalkStep> RandomWalk1D<'g, G, S> {
+  /// Constructs a new walk, unbounded.
+  #[inline]
+  pub fn new(gen: &'g mut G, start: S::Coord, step: S) -> Self {
+    Self { gen, step, pos: start, bounds: None }
+  }
+
+  /// Keeps the walk within bounds.
+  ///
+  /// ## Panics
+  /// * If the low bound is more than the high bound (or either is NaN).
+  #[inline]
+  pub fn with_bounds(mut self, bounds: WalkBounds<S::Coord>) -> Self {
+    assert!(bounds.is_valid(), "RandomWalk1D::with_bounds> Invalid bounds.");
+    self.bounds = Some(bounds);
+    self
+  }
+
+  /// The current position.
+  #[inline]
+  pub fn position(&self) -> S::Coord {
+    self.pos
+  }
+}
+
+impl<'g, G: Gen32 + ?Sized, S: WalkStep> Iterator for RandomWalk1D<'g, G, S> {
+  type Item = S::Coord;
+
+  #[inline]
+  fn next(&mut self) -> Option<S::Coord> {
+    let step = self.step.step_1d(self.gen);
+    self.pos = S::advance(self.pos, step, self.bounds);
+    Some(self.pos)
+  }
+
+  #[inline]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    (usize::MAX, None)
+  }
+}
+
+/// A random walk across a plane.
+///
+/// * Each call to `next` takes one step (picked by `S`) and gives the new
+///   position as `[x, y]`. The walk never ends, so use [`take`](Iterator::take)
+///   or similar.
+/// * Use [`LatticeStep`] for moving between grid cells (such as carving a
+///   "drunkard's walk" cave), or [`GaussianStep`] for continuous steps.
+/// * Optionally the walk can be kept within bounds, given separately for each
+///   axis. If the walk starts outside the bounds it's brought inside on the
+///   first step.
+#[derive(Debug)]
+pub struct RandomWalk2D<'g, G: Gen32 + ?Sized, S: WalkStep> {
+  gen: &'g mut G,
+  step: S,
+  pos: [S::Coord; 2],
+  bounds: [Option<WalkBounds<S::Coord>>; 2],
+}
+
+impl<'g, G: Gen32 + ?Sized, S: WalkStep> RandomWalk2D<'g, G, S> {
+  /// Constructs a new walk, unbounded.
+  #[inline]
+  pub fn new(gen: &'g mut G, start: [S::Coord; 2], step: S) -> Self {
+    Self { gen, step, pos: start, bounds: [None, None] }
+  }
+
+  /// Keeps the walk within bounds, as `[x_bounds, y_bounds]`.
+  ///
+  /// ## Panics
+  /// * If a low bound is more than its high bound (or either is NaN).
+  #[inline]
+  pub fn with_bounds(mut self, bounds: [WalkBounds<S::Coord>; 2]) -> Self {
+    assert!(bounds.iter().all(WalkBounds::is_valid), "RandomWalk2D::with_bounds> Invalid bounds.");
+    self.bounds = [Some(bounds[0]), Some(bounds[1])];
+    self
+  }
+
+  /// The current position.
+  #[inline]
+  pub fn position(&self) -> [S::Coord; 2] {
+    self.pos
+  }
+}
+
+impl<'g, G: Gen32 + ?Sized, S: WalkStep> Iterator for RandomWalk2D<'g, G, S> {
+  type Item = [S::Coord; 2];
+
+  #[inline]
+  fn next(&mut self) -> Option<[S::Coord; 2]> {
+    let [dx, dy] = self.step.step_2d(self.gen);
+    self.pos =
+      [S::advance(self.pos[0], dx, self.bounds[0]), S::advance(self.pos[1], dy, self.bounds[1])];
+    Some(self.pos)
+  }
+
+  #[inline]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    (usize::MAX, None)
+  }
+}
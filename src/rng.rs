@@ -34,8 +34,11 @@ make_jump_lcgX!(jump_lcg32_32, u32);
 /// * The methods on this type are quite minimal. You're expected to use the
 ///   [`Gen32`] trait to provide most of the useful operations.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RNG {
+  #[cfg_attr(feature = "serde", serde(rename = "state_v1"))]
   state: u32,
+  #[cfg_attr(feature = "serde", serde(rename = "inc_v1"))]
   inc: u32,
 }
 
@@ -49,6 +52,33 @@ impl RNG {
     Self { state, inc }
   }
 
+  /// The stream this generator is on.
+  ///
+  /// This is the `inc` value that was given to [`seed`](Self::seed) (or
+  /// [`set_stream`](Self::set_stream)), except that the top bit is always 0.
+  #[inline]
+  pub const fn stream(&self) -> u32 {
+    self.inc >> 1
+  }
+
+  /// Moves the generator to another stream, keeping the current state.
+  ///
+  /// Generators with the same state on different streams give unrelated
+  /// output, so one seeded generator can be split into many independent
+  /// sub-generators this way. The top bit of `inc` is ignored.
+  #[inline]
+  pub fn set_stream(&mut self, inc: u32) {
+    self.inc = (inc << 1) | 1;
+  }
+
+  /// Gives this generator moved to another stream, see
+  /// [`set_stream`](Self::set_stream).
+  #[inline]
+  #[must_use]
+  pub const fn with_stream(self, inc: u32) -> Self {
+    Self { state: self.state, inc: (inc << 1) | 1 }
+  }
+
   /// Gets the next 32-bits of output.
   #[inline]
   pub fn next_u32(&mut self) -> u32 {
@@ -0,0 +1,236 @@
+use super::*;
+
+const ROMU_MULTIPLIER_64: u64 = 15241094284759029579;
+const ROMU_MULTIPLIER_32: u32 = 3323815723;
+
+/// A [Romu](https://www.romu-random.org/) Trio generator with 192 bits of state
+/// and 64 bits of output per step.
+///
+/// * Each step is one multiply, two subtracts, and two rotates, with the
+///   multiply off the critical path. This is among the fastest generators
+///   available on 64-bit targets.
+/// * Romu generators are not one big cycle. The period depends on the seed, but
+///   is so large for this size of state that short cycles are never seen in
+///   practice.
+/// * The all-zero state is the one state the generator can never leave, so
+///   [`seed`](Self::seed) will never produce it.
+/// * If you want to exactly save/restore a generator use the `Into` and `From`
+///   impls to convert the generator into and from a `[u64; 3]`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RomuTrio {
+  #[cfg_attr(feature = "serde", serde(rename = "s_v1"))]
+  s: [u64; 3],
+}
+
+impl RomuTrio {
+  /// Seed a new generator.
+  ///
+  /// The seed is expanded into the full state with [`SplitMix64`].
+  pub const fn seed(seed: u64) -> Self {
+    let s = [splitmix64_nth(seed, 1), splitmix64_nth(seed, 2), splitmix64_nth(seed, 3)];
+    if (s[0] | s[1] | s[2]) == 0 {
+      Self { s: [1, 2, 3] }
+    } else {
+      Self { s }
+    }
+  }
+
+  /// Gets the next 64-bits of output.
+  #[inline]
+  pub fn next_u64(&mut self) -> u64 {
+    let [xp, yp, zp] = self.s;
+    let x = ROMU_MULTIPLIER_64.wrapping_mul(zp);
+    let y = yp.wrapping_sub(xp).rotate_left(12);
+    let z = zp.wrapping_sub(yp).rotate_left(44);
+    self.s = [x, y, z];
+    xp
+  }
+}
+
+impl Default for RomuTrio {
+  fn default() -> Self {
+    const THE_DEFAULT: RomuTrio = RomuTrio::seed(DEFAULT_PCG_SEED as _);
+    THE_DEFAULT
+  }
+}
+
+impl From<[u64; 3]> for RomuTrio {
+  fn from(s: [u64; 3]) -> Self {
+    Self { s }
+  }
+}
+
+impl From<RomuTrio> for [u64; 3] {
+  fn from(romu: RomuTrio) -> Self {
+    romu.s
+  }
+}
+
+impl Gen64 for RomuTrio {
+  fn next_u64(&mut self) -> u64 {
+    RomuTrio::next_u64(self)
+  }
+}
+
+impl Gen32 for RomuTrio {
+  fn next_u32(&mut self) -> u32 {
+    (RomuTrio::next_u64(self) >> 32) as u32
+  }
+
+  fn next_u64(&mut self) -> u64 {
+    RomuTrio::next_u64(self)
+  }
+}
+
+/// A [Romu](https://www.romu-random.org/) Quad generator with 256 bits of state
+/// and 64 bits of output per step.
+///
+/// * This is slightly slower than [`RomuTrio`], but the larger state makes it
+///   the better choice when a very large amount of output is needed.
+/// * The all-zero state is the one state the generator can never leave, so
+///   [`seed`](Self::seed) will never produce it.
+/// * If you want to exactly save/restore a generator use the `Into` and `From`
+///   impls to convert the generator into and from a `[u64; 4]`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RomuQuad {
+  #[cfg_attr(feature = "serde", serde(rename = "s_v1"))]
+  s: [u64; 4],
+}
+
+impl RomuQuad {
+  /// Seed a new generator.
+  ///
+  /// The seed is expanded into the full state with [`SplitMix64`].
+  pub const fn seed(seed: u64) -> Self {
+    let s = [
+      splitmix64_nth(seed, 1),
+      splitmix64_nth(seed, 2),
+      splitmix64_nth(seed, 3),
+      splitmix64_nth(seed, 4),
+    ];
+    if (s[0] | s[1] | s[2] | s[3]) == 0 {
+      Self { s: [1, 2, 3, 4] }
+    } else {
+      Self { s }
+    }
+  }
+
+  /// Gets the next 64-bits of output.
+  #[inline]
+  pub fn next_u64(&mut self) -> u64 {
+    let [wp, xp, yp, zp] = self.s;
+    let w = ROMU_MULTIPLIER_64.wrapping_mul(zp);
+    let x = zp.wrapping_add(wp.rotate_left(52));
+    let y = yp.wrapping_sub(xp);
+    let z = yp.wrapping_add(wp).rotate_left(19);
+    self.s = [w, x, y, z];
+    xp
+  }
+}
+
+impl Default for RomuQuad {
+  fn default() -> Self {
+    const THE_DEFAULT: RomuQuad = RomuQuad::seed(DEFAULT_PCG_SEED as _);
+    THE_DEFAULT
+  }
+}
+
+impl From<[u64; 4]> for RomuQuad {
+  fn from(s: [u64; 4]) -> Self {
+    Self { s }
+  }
+}
+
+impl From<RomuQuad> for [u64; 4] {
+  fn from(romu: RomuQuad) -> Self {
+    romu.s
+  }
+}
+
+impl Gen64 for RomuQuad {
+  fn next_u64(&mut self) -> u64 {
+    RomuQuad::next_u64(self)
+  }
+}
+
+impl Gen32 for RomuQuad {
+  fn next_u32(&mut self) -> u32 {
+    (RomuQuad::next_u64(self) >> 32) as u32
+  }
+
+  fn next_u64(&mut self) -> u64 {
+    RomuQuad::next_u64(self)
+  }
+}
+
+/// A [Romu](https://www.romu-random.org/) Trio32 generator with 96 bits of
+/// state and 32 bits of output per step.
+///
+/// * All of the math is done with `u32` values, so this is the Romu to use on
+///   32-bit targets.
+/// * The all-zero state is the one state the generator can never leave, so
+///   [`seed`](Self::seed) will never produce it.
+/// * If you want to exactly save/restore a generator use the `Into` and `From`
+///   impls to convert the generator into and from a `[u32; 3]`.
+/// * The methods on this type are quite minimal. You're expected to use the
+///   [`Gen32`] trait to provide most of the useful operations.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RomuTrio32 {
+  #[cfg_attr(feature = "serde", serde(rename = "s_v1"))]
+  s: [u32; 3],
+}
+
+impl RomuTrio32 {
+  /// Seed a new generator.
+  ///
+  /// The seed is expanded into the full state with [`SplitMix64`].
+  pub const fn seed(seed: u64) -> Self {
+    let a = splitmix64_nth(seed, 1);
+    let b = splitmix64_nth(seed, 2);
+    let s = [a as u32, (a >> 32) as u32, b as u32];
+    if (s[0] | s[1] | s[2]) == 0 {
+      Self { s: [1, 2, 3] }
+    } else {
+      Self { s }
+    }
+  }
+
+  /// Gets the next 32-bits of output.
+  #[inline]
+  pub fn next_u32(&mut self) -> u32 {
+    let [xp, yp, zp] = self.s;
+    let x = ROMU_MULTIPLIER_32.wrapping_mul(zp);
+    let y = yp.wrapping_sub(xp).rotate_left(6);
+    let z = zp.wrapping_sub(yp).rotate_left(22);
+    self.s = [x, y, z];
+    xp
+  }
+}
+
+impl Default for RomuTrio32 {
+  fn default() -> Self {
+    const THE_DEFAULT: RomuTrio32 = RomuTrio32::seed(DEFAULT_PCG_SEED as _);
+    THE_DEFAULT
+  }
+}
+
+impl From<[u32; 3]> for RomuTrio32 {
+  fn from(s: [u32; 3]) -> Self {
+    Self { s }
+  }
+}
+
+impl From<RomuTrio32> for [u32; 3] {
+  fn from(romu: RomuTrio32) -> Self {
+    romu.s
+  }
+}
+
+impl Gen32 for RomuTrio32 {
+  fn next_u32(&mut self) -> u32 {
+    RomuTrio32::next_u32(self)
+  }
+}
@@ -0,0 +1,50 @@
+//! Serde helpers for arrays longer than the 32 elements that serde supports
+//! directly. Use with `#[serde(with = "crate::serde_array")]`.
+
+use core::{fmt, marker::PhantomData};
+use serde::{
+  de::{Error, SeqAccess, Visitor},
+  ser::SerializeTuple,
+  Deserialize, Deserializer, Serialize, Serializer,
+};
+
+pub(crate) fn serialize<S, T, const N: usize>(arr: &[T; N], s: S) -> Result<S::Ok, S::Error>
+where
+  S: Serializer,
+  T: Serialize,
+{
+  let mut tup = s.serialize_tuple(N)?;
+  for x in arr.iter() {
+    tup.serialize_element(x)?;
+  }
+  tup.end()
+}
+
+pub(crate) fn deserialize<'de, D, T, const N: usize>(d: D) -> Result<[T; N], D::Error>
+where
+  D: Deserializer<'de>,
+  T: Deserialize<'de> + Copy + Default,
+{
+  struct ArrayVisitor<T, const N: usize>(PhantomData<T>);
+
+  impl<'de, T, const N: usize> Visitor<'de> for ArrayVisitor<T, N>
+  where
+    T: Deserialize<'de> + Copy + Default,
+  {
+    type Value = [T; N];
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+      write!(f, "an array of length {}", N)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+      let mut arr = [T::default(); N];
+      for (i, x) in arr.iter_mut().enumerate() {
+        *x = seq.next_element()?.ok_or_else(|| A::Error::invalid_length(i, &self))?;
+      }
+      Ok(arr)
+    }
+  }
+
+  d.deserialize_tuple(N, ArrayVisitor::<T, N>(PhantomData))
+}
@@ -0,0 +1,81 @@
+use super::*;
+
+/// One step of SFC32: gives the new state and the output.
+#[must_use]
+#[inline(always)]
+const fn sfc32_step([a, b, c, counter]: [u32; 4]) -> ([u32; 4], u32) {
+  let out = a.wrapping_add(b).wrapping_add(counter);
+  let new_a = b ^ (b >> 9);
+  let new_b = c.wrapping_add(c << 3);
+  let new_c = c.rotate_left(21).wrapping_add(out);
+  ([new_a, new_b, new_c, counter.wrapping_add(1)], out)
+}
+
+/// Chris Doty-Humphrey's "Small Fast Counting" generator, with 128 bits of
+/// state and 32 bits of output per step.
+///
+/// * All of the math is done with `u32` values (adds, shifts, and a rotate), so
+///   this maps very well onto the ARM7TDMI.
+/// * This is not an LCG. Part of the state is a counter, so every seed is
+///   guaranteed a period of at least `2^32`, and the average period is about
+///   `2^127`.
+/// * If you want to exactly save/restore a generator use the `Into` and `From`
+///   impls to convert the generator into and from a `[u32; 4]`.
+/// * The methods on this type are quite minimal. You're expected to use the
+///   [`Gen32`] trait to provide most of the useful operations.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sfc32 {
+  #[cfg_attr(feature = "serde", serde(rename = "s_v1"))]
+  s: [u32; 4],
+}
+
+impl Sfc32 {
+  /// Seed a new generator.
+  ///
+  /// The counter starts at 1 and the generator is then stepped 12 times to mix
+  /// the seed values around, so "boring" seeds like `seed(0, 0, 0)` are fine.
+  pub const fn seed(a: u32, b: u32, c: u32) -> Self {
+    let mut s = [a, b, c, 1];
+    let mut i = 0;
+    while i < 12 {
+      s = sfc32_step(s).0;
+      i += 1;
+    }
+    Self { s }
+  }
+
+  /// Gets the next 32-bits of output.
+  #[inline]
+  pub fn next_u32(&mut self) -> u32 {
+    let (s, out) = sfc32_step(self.s);
+    self.s = s;
+    out
+  }
+}
+
+impl Default for Sfc32 {
+  fn default() -> Self {
+    const THE_DEFAULT: Sfc32 =
+      Sfc32::seed(DEFAULT_PCG_SEED as _, (DEFAULT_PCG_SEED >> 32) as _, DEFAULT_PCG_INC as _);
+    THE_DEFAULT
+  }
+}
+
+impl From<[u32; 4]> for Sfc32 {
+  fn from(s: [u32; 4]) -> Self {
+    Self { s }
+  }
+}
+
+impl From<Sfc32> for [u32; 4] {
+  fn from(sfc: Sfc32) -> Self {
+    sfc.s
+  }
+}
+
+impl Gen32 for Sfc32 {
+  fn next_u32(&mut self) -> u32 {
+    Sfc32::next_u32(self)
+  }
+}
@@ -0,0 +1,71 @@
+use super::*;
+
+/// Deals out every item once, in a random order, before reshuffling.
+///
+/// * This is the "7-bag" of many falling block games: each piece shows up
+///   exactly once per bag, so there are never long droughts or long streaks,
+///   but the order within a bag is still random.
+/// * A new bag starts out used up, so the first [`draw`](Self::draw) shuffles.
+///   Call [`reshuffle`](Self::reshuffle) if you want to [`peek`](Self::peek)
+///   before the first draw.
+/// * The items are kept in an array, so no allocation is needed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ShuffleBag<T, const N: usize> {
+  items: [T; N],
+  next: usize,
+}
+
+impl<T, const N: usize> ShuffleBag<T, N> {
+  /// Makes a bag holding the given items.
+  #[inline]
+  pub const fn new(items: [T; N]) -> Self {
+    Self { items, next: N }
+  }
+
+  /// All the items, in the current bag's order.
+  #[inline]
+  pub fn items(&self) -> &[T; N] {
+    &self.items
+  }
+
+  /// How many items are left before the bag is reshuffled.
+  #[inline]
+  pub const fn remaining(&self) -> usize {
+    N - self.next
+  }
+
+  /// The item that the next draw will give, or `None` if the bag is used up
+  /// (in which case the next draw reshuffles first).
+  #[inline]
+  pub fn peek(&self) -> Option<&T> {
+    self.items.get(self.next)
+  }
+
+  /// Puts every item back, so the next draw starts a fresh bag.
+  #[inline]
+  pub fn reset(&mut self) {
+    self.next = N;
+  }
+
+  /// Puts every item back and shuffles the bag right away.
+  #[inline]
+  pub fn reshuffle<G: Gen32>(&mut self, gen: &mut G) {
+    gen.shuffle(&mut self.items);
+    self.next = 0;
+  }
+
+  /// Draws the next item, reshuffling first if the bag is used up.
+  ///
+  /// ## Panics
+  /// * If the bag holds no items (`N` is 0).
+  #[inline]
+  pub fn draw<G: Gen32>(&mut self, gen: &mut G) -> &T {
+    assert!(N > 0, "ShuffleBag::draw> The bag has no items.");
+    if self.next >= N {
+      self.reshuffle(gen);
+    }
+    let out = &self.items[self.next];
+    self.next += 1;
+    out
+  }
+}
@@ -0,0 +1,107 @@
+use super::*;
+
+const SPLITMIX64_GAMMA: u64 = 0x9e3779b97f4a7c15;
+
+/// The SplitMix64 output function.
+///
+/// This is a good 64-bit integer hash all on its own.
+#[must_use]
+#[inline(always)]
+pub(crate) const fn splitmix64_mix(mut z: u64) -> u64 {
+  z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+  z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+  z ^ (z >> 31)
+}
+
+/// Gives the `n`th output (starting from 1) of a [`SplitMix64`] seeded with
+/// `seed`.
+#[must_use]
+#[inline(always)]
+pub(crate) const fn splitmix64_nth(seed: u64, n: u64) -> u64 {
+  splitmix64_mix(seed.wrapping_add(SPLITMIX64_GAMMA.wrapping_mul(n)))
+}
+
+/// A [SplitMix64](https://prng.di.unimi.it/splitmix64.c) generator with 64 bits
+/// of state and 64 bits of output per step.
+///
+/// * Every `u64` is a fine seed, including 0.
+/// * This is the recommended way to expand a single integer into the larger
+///   state of another generator. See [`Xoshiro256pp::from_splitmix`] and
+///   [`Xoshiro128pp::from_splitmix`].
+/// * If you want to exactly save/restore a generator use the `Into` and `From`
+///   impls to convert the generator into and from a `u64`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SplitMix64 {
+  #[cfg_attr(feature = "serde", serde(rename = "state_v1"))]
+  state: u64,
+}
+
+impl SplitMix64 {
+  /// Seed a new generator.
+  pub const fn seed(seed: u64) -> Self {
+    Self { state: seed }
+  }
+
+  /// Gets the next 64-bits of output.
+  #[inline]
+  pub fn next_u64(&mut self) -> u64 {
+    self.state = self.state.wrapping_add(SPLITMIX64_GAMMA);
+    splitmix64_mix(self.state)
+  }
+}
+
+impl Default for SplitMix64 {
+  fn default() -> Self {
+    Self::seed(DEFAULT_PCG_SEED as _)
+  }
+}
+
+impl From<u64> for SplitMix64 {
+  fn from(state: u64) -> Self {
+    Self { state }
+  }
+}
+
+impl From<SplitMix64> for u64 {
+  fn from(sm: SplitMix64) -> Self {
+    sm.state
+  }
+}
+
+impl Gen64 for SplitMix64 {
+  fn next_u64(&mut self) -> u64 {
+    SplitMix64::next_u64(self)
+  }
+}
+
+impl Gen32 for SplitMix64 {
+  fn next_u32(&mut self) -> u32 {
+    (SplitMix64::next_u64(self) >> 32) as u32
+  }
+
+  fn next_u64(&mut self) -> u64 {
+    SplitMix64::next_u64(self)
+  }
+}
+
+impl Xoshiro256pp {
+  /// Seeds a new generator from the first four outputs of a [`SplitMix64`].
+  pub const fn from_splitmix(seed: u64) -> Self {
+    Self::seed([
+      splitmix64_nth(seed, 1),
+      splitmix64_nth(seed, 2),
+      splitmix64_nth(seed, 3),
+      splitmix64_nth(seed, 4),
+    ])
+  }
+}
+
+impl Xoshiro128pp {
+  /// Seeds a new generator from the first two outputs of a [`SplitMix64`].
+  pub const fn from_splitmix(seed: u64) -> Self {
+    let a = splitmix64_nth(seed, 1);
+    let b = splitmix64_nth(seed, 2);
+    Self::seed([a as u32, (a >> 32) as u32, b as u32, (b >> 32) as u32])
+  }
+}
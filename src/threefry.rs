@@ -0,0 +1,261 @@
+use super::*;
+
+const SKEIN_KS_PARITY64: u64 = 0x1BD11BDAA9FC1A22;
+const SKEIN_KS_PARITY32: u32 = 0x1BD11BDA;
+
+const THREEFRY_ROTATIONS_2X64: [u32; 8] = [16, 42, 12, 31, 16, 32, 24, 21];
+
+const THREEFRY_ROTATIONS_4X32: [[u32; 2]; 8] =
+  [[10, 26], [11, 21], [13, 27], [23, 5], [6, 20], [17, 11], [25, 10], [18, 20]];
+
+/// A [Threefry](https://www.thesalmons.org/john/random123/) 2x64-20
+/// counter-based generator, with 64 bits of output per step.
+///
+/// * This works like [`Philox4x32`], but the rounds use only adds, rotates, and
+///   xors, with no multiplies at all. That's attractive on hardware without a
+///   fast multiplier.
+/// * Each counter value gives two outputs, see [`block`](Self::block).
+/// * If you want to exactly save/restore a generator use [`key`](Self::key) and
+///   [`counter`](Self::counter), then call [`seed`](Self::seed) to restore.
+///   [`word_index`](Self::word_index) gives the position within the current
+///   block.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Threefry2x64 {
+  #[cfg_attr(feature = "serde", serde(rename = "key_v1"))]
+  key: u128,
+  /// The counter of the next block to compute.
+  #[cfg_attr(feature = "serde", serde(rename = "counter_v1"))]
+  counter: u128,
+  #[cfg_attr(feature = "serde", serde(rename = "buffer_v1"))]
+  buffer: [u64; 2],
+  /// Index of the next unused word in `buffer`, 2 if it's all used.
+  #[cfg_attr(feature = "serde", serde(rename = "index_v1"))]
+  index: usize,
+}
+
+impl Threefry2x64 {
+  /// Makes a generator that will next output the block for `counter`.
+  pub const fn seed(key: u128, counter: u128) -> Self {
+    Self { key, counter, buffer: [0; 2], index: 2 }
+  }
+
+  /// Computes the two output words for a given key and counter.
+  #[must_use]
+  pub const fn block(key: u128, counter: u128) -> [u64; 2] {
+    let k0 = key as u64;
+    let k1 = (key >> 64) as u64;
+    let ks = [k0, k1, SKEIN_KS_PARITY64 ^ k0 ^ k1];
+    let mut x0 = (counter as u64).wrapping_add(ks[0]);
+    let mut x1 = ((counter >> 64) as u64).wrapping_add(ks[1]);
+    let mut r = 0;
+    while r < 20 {
+      x0 = x0.wrapping_add(x1);
+      x1 = x1.rotate_left(THREEFRY_ROTATIONS_2X64[r % 8]);
+      x1 ^= x0;
+      if r % 4 == 3 {
+        let s = (r + 1) / 4;
+        x0 = x0.wrapping_add(ks[s % 3]);
+        x1 = x1.wrapping_add(ks[(s + 1) % 3]).wrapping_add(s as u64);
+      }
+      r += 1;
+    }
+    [x0, x1]
+  }
+
+  /// Gets the next 64-bits of output.
+  #[inline]
+  pub fn next_u64(&mut self) -> u64 {
+    if self.index >= 2 {
+      self.buffer = Self::block(self.key, self.counter);
+      self.counter = self.counter.wrapping_add(1);
+      self.index = 0;
+    }
+    let out = self.buffer[self.index];
+    self.index += 1;
+    out
+  }
+
+  /// The key of this generator.
+  #[inline]
+  pub const fn key(&self) -> u128 {
+    self.key
+  }
+
+  /// The counter of the current block.
+  ///
+  /// If no output has been taken from this block yet, this is the block that
+  /// the next output will come from.
+  #[inline]
+  pub const fn counter(&self) -> u128 {
+    if self.index >= 2 {
+      self.counter
+    } else {
+      self.counter.wrapping_sub(1)
+    }
+  }
+
+  /// How many words of the current block have been used, `0 .. 2`.
+  #[inline]
+  pub const fn word_index(&self) -> usize {
+    self.index % 2
+  }
+
+  /// Moves to the start of the block for a given counter.
+  #[inline]
+  pub fn set_counter(&mut self, counter: u128) {
+    self.counter = counter;
+    self.index = 2;
+  }
+}
+
+impl Default for Threefry2x64 {
+  fn default() -> Self {
+    const THE_DEFAULT: Threefry2x64 = Threefry2x64::seed(DEFAULT_PCG_SEED, 0);
+    THE_DEFAULT
+  }
+}
+
+impl Gen64 for Threefry2x64 {
+  fn next_u64(&mut self) -> u64 {
+    Threefry2x64::next_u64(self)
+  }
+}
+
+impl Gen32 for Threefry2x64 {
+  fn next_u32(&mut self) -> u32 {
+    (Threefry2x64::next_u64(self) >> 32) as u32
+  }
+
+  fn next_u64(&mut self) -> u64 {
+    Threefry2x64::next_u64(self)
+  }
+}
+
+/// A [Threefry](https://www.thesalmons.org/john/random123/) 4x32-20
+/// counter-based generator, with 32 bits of output per step.
+///
+/// * This is the all-32-bit version of [`Threefry2x64`], with a 128-bit key and
+///   four outputs per counter value, see [`block`](Self::block).
+/// * If you want to exactly save/restore a generator use [`key`](Self::key) and
+///   [`counter`](Self::counter), then call [`seed`](Self::seed) to restore.
+///   [`word_index`](Self::word_index) gives the position within the current
+///   block.
+/// * The methods on this type are quite minimal. You're expected to use the
+///   [`Gen32`] trait to provide most of the useful operations.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Threefry4x32 {
+  #[cfg_attr(feature = "serde", serde(rename = "key_v1"))]
+  key: u128,
+  /// The counter of the next block to compute.
+  #[cfg_attr(feature = "serde", serde(rename = "counter_v1"))]
+  counter: u128,
+  #[cfg_attr(feature = "serde", serde(rename = "buffer_v1"))]
+  buffer: [u32; 4],
+  /// Index of the next unused word in `buffer`, 4 if it's all used.
+  #[cfg_attr(feature = "serde", serde(rename = "index_v1"))]
+  index: usize,
+}
+
+impl Threefry4x32 {
+  /// Makes a generator that will next output the block for `counter`.
+  pub const fn seed(key: u128, counter: u128) -> Self {
+    Self { key, counter, buffer: [0; 4], index: 4 }
+  }
+
+  /// Computes the four output words for a given key and counter.
+  #[must_use]
+  pub const fn block(key: u128, counter: u128) -> [u32; 4] {
+    let k = [key as u32, (key >> 32) as u32, (key >> 64) as u32, (key >> 96) as u32];
+    let ks = [k[0], k[1], k[2], k[3], SKEIN_KS_PARITY32 ^ k[0] ^ k[1] ^ k[2] ^ k[3]];
+    let mut x = [
+      (counter as u32).wrapping_add(ks[0]),
+      ((counter >> 32) as u32).wrapping_add(ks[1]),
+      ((counter >> 64) as u32).wrapping_add(ks[2]),
+      ((counter >> 96) as u32).wrapping_add(ks[3]),
+    ];
+    let mut r = 0;
+    while r < 20 {
+      let [ra, rb] = THREEFRY_ROTATIONS_4X32[r % 8];
+      if r % 2 == 0 {
+        x[0] = x[0].wrapping_add(x[1]);
+        x[1] = x[1].rotate_left(ra) ^ x[0];
+        x[2] = x[2].wrapping_add(x[3]);
+        x[3] = x[3].rotate_left(rb) ^ x[2];
+      } else {
+        x[0] = x[0].wrapping_add(x[3]);
+        x[3] = x[3].rotate_left(ra) ^ x[0];
+        x[2] = x[2].wrapping_add(x[1]);
+        x[1] = x[1].rotate_left(rb) ^ x[2];
+      }
+      if r % 4 == 3 {
+        let s = (r + 1) / 4;
+        x[0] = x[0].wrapping_add(ks[s % 5]);
+        x[1] = x[1].wrapping_add(ks[(s + 1) % 5]);
+        x[2] = x[2].wrapping_add(ks[(s + 2) % 5]);
+        x[3] = x[3].wrapping_add(ks[(s + 3) % 5]).wrapping_add(s as u32);
+      }
+      r += 1;
+    }
+    x
+  }
+
+  /// Gets the next 32-bits of output.
+  #[inline]
+  pub fn next_u32(&mut self) -> u32 {
+    if self.index >= 4 {
+      self.buffer = Self::block(self.key, self.counter);
+      self.counter = self.counter.wrapping_add(1);
+      self.index = 0;
+    }
+    let out = self.buffer[self.index];
+    self.index += 1;
+    out
+  }
+
+  /// The key of this generator.
+  #[inline]
+  pub const fn key(&self) -> u128 {
+    self.key
+  }
+
+  /// The counter of the current block.
+  ///
+  /// If no output has been taken from this block yet, this is the block that
+  /// the next output will come from.
+  #[inline]
+  pub const fn counter(&self) -> u128 {
+    if self.index >= 4 {
+      self.counter
+    } else {
+      self.counter.wrapping_sub(1)
+    }
+  }
+
+  /// How many words of the current block have been used, `0 .. 4`.
+  #[inline]
+  pub const fn word_index(&self) -> usize {
+    self.index % 4
+  }
+
+  /// Moves to the start of the block for a given counter.
+  #[inline]
+  pub fn set_counter(&mut self, counter: u128) {
+    self.counter = counter;
+    self.index = 4;
+  }
+}
+
+impl Default for Threefry4x32 {
+  fn default() -> Self {
+    const THE_DEFAULT: Threefry4x32 = Threefry4x32::seed(DEFAULT_PCG_SEED, 0);
+    THE_DEFAULT
+  }
+}
+
+impl Gen32 for Threefry4x32 {
+  fn next_u32(&mut self) -> u32 {
+    Threefry4x32::next_u32(self)
+  }
+}
@@ -0,0 +1,117 @@
+use super::*;
+
+const TINYMT32_MASK: u32 = 0x7fff_ffff;
+const TINYMT32_STANDARD_PARAMS: [u32; 3] = [0x8f7011ee, 0xfc78ff1f, 0x3793fdff];
+
+/// The [TinyMT](http://www.math.sci.hiroshima-u.ac.jp/m-mat/MT/TINYMT/) 32-bit
+/// generator, with 127 bits of state and 32 bits of output per step.
+///
+/// * This is a small cousin of [`Mt19937`] that's common on embedded systems
+///   and in some file formats. The output exactly matches the reference
+///   `tinymt32_init` and `tinymt32_generate_uint32` functions.
+/// * TinyMT has a family of parameter sets. [`seed`](Self::seed) uses the
+///   standard set from the reference check program, and
+///   [`seed_with_params`](Self::seed_with_params) accepts any other set made by
+///   the `TinyMTDC` tool.
+/// * If you want to exactly save/restore a generator use the `Into` and `From`
+///   impls to convert the generator into and from a `[u32; 7]` (the four status
+///   words, then `mat1`, `mat2`, and `tmat`).
+/// * The methods on this type are quite minimal. You're expected to use the
+///   [`Gen32`] trait to provide most of the useful operations.
+///
+/// ```
+/// # use randomize::TinyMt32;
+/// let mut tiny = TinyMt32::seed(1);
+/// assert_eq!(tiny.next_u32(), 2545341989);
+/// assert_eq!(tiny.next_u32(), 981918433);
+/// assert_eq!(tiny.next_u32(), 3715302833);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TinyMt32 {
+  #[cfg_attr(feature = "serde", serde(rename = "status_v1"))]
+  status: [u32; 4],
+  #[cfg_attr(feature = "serde", serde(rename = "mat1_v1"))]
+  mat1: u32,
+  #[cfg_attr(feature = "serde", serde(rename = "mat2_v1"))]
+  mat2: u32,
+  #[cfg_attr(feature = "serde", serde(rename = "tmat_v1"))]
+  tmat: u32,
+}
+
+impl TinyMt32 {
+  /// Seed a new generator with the standard parameter set.
+  pub const fn seed(seed: u32) -> Self {
+    let [mat1, mat2, tmat] = TINYMT32_STANDARD_PARAMS;
+    Self::seed_with_params(seed, mat1, mat2, tmat)
+  }
+
+  /// Seed a new generator with a specific parameter set.
+  pub const fn seed_with_params(seed: u32, mat1: u32, mat2: u32, tmat: u32) -> Self {
+    let mut s = [seed, mat1, mat2, tmat];
+    let mut i = 1;
+    while i < 8 {
+      let prev = s[(i - 1) & 3];
+      s[i & 3] ^= (i as u32).wrapping_add(1812433253_u32.wrapping_mul(prev ^ (prev >> 30)));
+      i += 1;
+    }
+    if (s[0] & TINYMT32_MASK) == 0 && s[1] == 0 && s[2] == 0 && s[3] == 0 {
+      s = [b'T' as u32, b'I' as u32, b'N' as u32, b'Y' as u32];
+    }
+    let mut out = Self { status: s, mat1, mat2, tmat };
+    i = 0;
+    while i < 8 {
+      out.status = out.next_status();
+      i += 1;
+    }
+    out
+  }
+
+  /// Computes the next status words.
+  #[must_use]
+  #[inline(always)]
+  const fn next_status(&self) -> [u32; 4] {
+    let [s0, s1, s2, s3] = self.status;
+    let mut x = (s0 & TINYMT32_MASK) ^ s1 ^ s2;
+    x ^= x << 1;
+    let y = s3 ^ (s3 >> 1) ^ x;
+    let mask = (y & 1).wrapping_neg();
+    [s1, s2 ^ (mask & self.mat1), x ^ (y << 10) ^ (mask & self.mat2), y]
+  }
+
+  /// Gets the next 32-bits of output.
+  #[inline]
+  pub fn next_u32(&mut self) -> u32 {
+    self.status = self.next_status();
+    let [s0, _, s2, s3] = self.status;
+    let t1 = s0.wrapping_add(s2 >> 8);
+    let t0 = s3 ^ t1;
+    t0 ^ ((t1 & 1).wrapping_neg() & self.tmat)
+  }
+}
+
+impl Default for TinyMt32 {
+  fn default() -> Self {
+    const THE_DEFAULT: TinyMt32 = TinyMt32::seed(DEFAULT_PCG_SEED as _);
+    THE_DEFAULT
+  }
+}
+
+impl From<[u32; 7]> for TinyMt32 {
+  fn from([s0, s1, s2, s3, mat1, mat2, tmat]: [u32; 7]) -> Self {
+    Self { status: [s0, s1, s2, s3], mat1, mat2, tmat }
+  }
+}
+
+impl From<TinyMt32> for [u32; 7] {
+  fn from(tiny: TinyMt32) -> Self {
+    let [s0, s1, s2, s3] = tiny.status;
+    [s0, s1, s2, s3, tiny.mat1, tiny.mat2, tiny.tmat]
+  }
+}
+
+impl Gen32 for TinyMt32 {
+  fn next_u32(&mut self) -> u32 {
+    TinyMt32::next_u32(self)
+  }
+}
@@ -0,0 +1,69 @@
+use super::*;
+
+const WYRAND_ADD: u64 = 0xa0761d6478bd642f;
+const WYRAND_XOR: u64 = 0xe7037ed1a0b428db;
+
+/// A [wyrand](https://github.com/wangyi-fudan/wyhash) generator with 64 bits of
+/// state and 64 bits of output per step.
+///
+/// * Each step is one add and one `64x64->128` multiply, so this is extremely
+///   fast on 64-bit targets. On 32-bit targets that wide multiply is costly, so
+///   prefer [`RNG`] or [`Xoshiro128pp`] there.
+/// * Every `u64` is a fine seed, including 0.
+/// * If you want to exactly save/restore a generator use the `Into` and `From`
+///   impls to convert the generator into and from a `u64`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WyRand {
+  #[cfg_attr(feature = "serde", serde(rename = "state_v1"))]
+  state: u64,
+}
+
+impl WyRand {
+  /// Seed a new generator.
+  pub const fn seed(seed: u64) -> Self {
+    Self { state: seed }
+  }
+
+  /// Gets the next 64-bits of output.
+  #[inline]
+  pub fn next_u64(&mut self) -> u64 {
+    self.state = self.state.wrapping_add(WYRAND_ADD);
+    let t = (self.state as u128).wrapping_mul((self.state ^ WYRAND_XOR) as u128);
+    ((t >> 64) as u64) ^ (t as u64)
+  }
+}
+
+impl Default for WyRand {
+  fn default() -> Self {
+    Self::seed(DEFAULT_PCG_SEED as _)
+  }
+}
+
+impl From<u64> for WyRand {
+  fn from(state: u64) -> Self {
+    Self { state }
+  }
+}
+
+impl From<WyRand> for u64 {
+  fn from(wy: WyRand) -> Self {
+    wy.state
+  }
+}
+
+impl Gen64 for WyRand {
+  fn next_u64(&mut self) -> u64 {
+    WyRand::next_u64(self)
+  }
+}
+
+impl Gen32 for WyRand {
+  fn next_u32(&mut self) -> u32 {
+    (WyRand::next_u64(self) >> 32) as u32
+  }
+
+  fn next_u64(&mut self) -> u64 {
+    WyRand::next_u64(self)
+  }
+}
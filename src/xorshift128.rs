@@ -0,0 +1,81 @@
+use super::*;
+
+/// The seed values from Marsaglia's paper.
+const XORSHIFT128_MARSAGLIA_SEED: [u32; 4] = [123456789, 362436069, 521288629, 88675123];
+
+/// George Marsaglia's [xorshift128](https://www.jstatsoft.org/article/view/v008i14)
+/// generator, with 128 bits of state and 32 bits of output per step.
+///
+/// * This is mostly here for porting old code (many games used this exact
+///   generator) while keeping the same sequences. The output matches the
+///   `xor128` function from the paper exactly.
+/// * For new code, prefer [`Xoshiro128pp`], which is about as fast but has much
+///   better statistical quality.
+/// * The all-zero state is the one state the generator can never leave, so
+///   [`seed`](Self::seed) will never produce it.
+/// * The [`Default`] generator uses the seed values from the paper.
+/// * If you want to exactly save/restore a generator use the `Into` and `From`
+///   impls to convert the generator into and from a `[u32; 4]`.
+///
+/// ```
+/// # use randomize::XorShift128;
+/// let mut xs = XorShift128::default();
+/// assert_eq!(xs.next_u32(), 3701687786);
+/// assert_eq!(xs.next_u32(), 458299110);
+/// assert_eq!(xs.next_u32(), 2500872618);
+/// assert_eq!(xs.next_u32(), 3633119408);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct XorShift128 {
+  #[cfg_attr(feature = "serde", serde(rename = "s_v1"))]
+  s: [u32; 4],
+}
+
+impl XorShift128 {
+  /// Seed a new generator.
+  ///
+  /// The words are the `x`, `y`, `z`, and `w` values from the paper, in that
+  /// order. If the seed is all zeros the default state is used instead.
+  pub const fn seed(seed: [u32; 4]) -> Self {
+    if (seed[0] | seed[1] | seed[2] | seed[3]) == 0 {
+      Self { s: XORSHIFT128_MARSAGLIA_SEED }
+    } else {
+      Self { s: seed }
+    }
+  }
+
+  /// Gets the next 32-bits of output.
+  #[inline]
+  pub fn next_u32(&mut self) -> u32 {
+    let [x, y, z, w] = self.s;
+    let t = x ^ (x << 11);
+    let new_w = w ^ (w >> 19) ^ (t ^ (t >> 8));
+    self.s = [y, z, w, new_w];
+    new_w
+  }
+}
+
+impl Default for XorShift128 {
+  fn default() -> Self {
+    Self { s: XORSHIFT128_MARSAGLIA_SEED }
+  }
+}
+
+impl From<[u32; 4]> for XorShift128 {
+  fn from(s: [u32; 4]) -> Self {
+    Self { s }
+  }
+}
+
+impl From<XorShift128> for [u32; 4] {
+  fn from(xs: XorShift128) -> Self {
+    xs.s
+  }
+}
+
+impl Gen32 for XorShift128 {
+  fn next_u32(&mut self) -> u32 {
+    XorShift128::next_u32(self)
+  }
+}
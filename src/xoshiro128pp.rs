@@ -0,0 +1,118 @@
+use super::*;
+
+const XOSHIRO128_JUMP: [u32; 4] = [0x8764000b, 0xf542d2d3, 0x6fa035c3, 0x77f2db5b];
+
+const XOSHIRO128_LONG_JUMP: [u32; 4] = [0xb523952e, 0x0b6f099f, 0xccf5a0ef, 0x1c580662];
+
+/// A [xoshiro128++](https://prng.di.unimi.it/) generator with 128 bits of
+/// state and 32 bits of output per step.
+///
+/// * All of the math is done with `u32` values, so this is fast on 32-bit
+///   targets such as the GBA.
+/// * The all-zero state is the one state the generator can never leave, so
+///   [`seed`](Self::seed) will never produce it.
+/// * If you want to exactly save/restore a generator use the `Into` and `From`
+///   impls to convert the generator into and from a `[u32; 4]`.
+/// * The methods on this type are quite minimal. You're expected to use the
+///   [`Gen32`] trait to provide most of the useful operations.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Xoshiro128pp {
+  #[cfg_attr(feature = "serde", serde(rename = "s_v1"))]
+  s: [u32; 4],
+}
+
+impl Xoshiro128pp {
+  /// Seed a new generator.
+  ///
+  /// If the seed is all zeros the default state is used instead.
+  pub const fn seed(seed: [u32; 4]) -> Self {
+    if (seed[0] | seed[1] | seed[2] | seed[3]) == 0 {
+      Self::DEFAULT
+    } else {
+      Self { s: seed }
+    }
+  }
+
+  const DEFAULT: Self = Self {
+    s: [
+      DEFAULT_PCG_SEED as u32,
+      (DEFAULT_PCG_SEED >> 32) as u32,
+      (DEFAULT_PCG_SEED >> 64) as u32,
+      (DEFAULT_PCG_SEED >> 96) as u32,
+    ],
+  };
+
+  /// Gets the next 32-bits of output.
+  #[inline]
+  pub fn next_u32(&mut self) -> u32 {
+    let [s0, s1, s2, s3] = self.s;
+    let out = s0.wrapping_add(s3).rotate_left(7).wrapping_add(s0);
+    let t = s1 << 9;
+    let s2 = s2 ^ s0;
+    let s3 = s3 ^ s1;
+    let s1 = s1 ^ s2;
+    let s0 = s0 ^ s3;
+    let s2 = s2 ^ t;
+    let s3 = s3.rotate_left(11);
+    self.s = [s0, s1, s2, s3];
+    out
+  }
+
+  /// Jumps the generator by `2^64` steps forward.
+  ///
+  /// This can produce `2^64` non-overlapping sub-sequences for parallel work.
+  #[inline]
+  pub fn jump(&mut self) {
+    self.jump_by_poly(&XOSHIRO128_JUMP)
+  }
+
+  /// Jumps the generator by `2^96` steps forward.
+  ///
+  /// This can produce `2^32` starting points, each of which can then use
+  /// [`jump`](Self::jump) to make its own sub-sequences.
+  #[inline]
+  pub fn long_jump(&mut self) {
+    self.jump_by_poly(&XOSHIRO128_LONG_JUMP)
+  }
+
+  fn jump_by_poly(&mut self, poly: &[u32; 4]) {
+    let mut acc = [0_u32; 4];
+    for word in poly.iter() {
+      for b in 0..32 {
+        if (word & (1_u32 << b)) != 0 {
+          acc[0] ^= self.s[0];
+          acc[1] ^= self.s[1];
+          acc[2] ^= self.s[2];
+          acc[3] ^= self.s[3];
+        }
+        self.next_u32();
+      }
+    }
+    self.s = acc;
+  }
+}
+
+impl Default for Xoshiro128pp {
+  fn default() -> Self {
+    Self::DEFAULT
+  }
+}
+
+impl From<[u32; 4]> for Xoshiro128pp {
+  fn from(s: [u32; 4]) -> Self {
+    Self { s }
+  }
+}
+
+impl From<Xoshiro128pp> for [u32; 4] {
+  fn from(x: Xoshiro128pp) -> Self {
+    x.s
+  }
+}
+
+impl Gen32 for Xoshiro128pp {
+  fn next_u32(&mut self) -> u32 {
+    Xoshiro128pp::next_u32(self)
+  }
+}
@@ -0,0 +1,129 @@
+use super::*;
+
+const XOSHIRO256_JUMP: [u64; 4] =
+  [0x180ec6d33cfd0aba, 0xd5a61266f0c9392c, 0xa9582618e03fc9aa, 0x39abdc4529b1661c];
+
+const XOSHIRO256_LONG_JUMP: [u64; 4] =
+  [0x76e15d3efefdcbbf, 0xc5004e441c522fb3, 0x77710069854ee241, 0x39109bb02acbe635];
+
+/// A [xoshiro256++](https://prng.di.unimi.it/) generator with 256 bits of
+/// state and 64 bits of output per step.
+///
+/// * The period is `2^256 - 1`, much larger than that of [`Pcg32`].
+/// * The all-zero state is the one state the generator can never leave, so
+///   [`seed`](Self::seed) will never produce it.
+/// * If you want to exactly save/restore a generator use the `Into` and `From`
+///   impls to convert the generator into and from a `[u64; 4]`.
+/// * You're expected to use the [`Gen64`] (or [`Gen32`]) trait to provide most
+///   of the useful operations.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Xoshiro256pp {
+  #[cfg_attr(feature = "serde", serde(rename = "s_v1"))]
+  s: [u64; 4],
+}
+
+impl Xoshiro256pp {
+  /// Seed a new generator.
+  ///
+  /// If the seed is all zeros the default state is used instead.
+  pub const fn seed(seed: [u64; 4]) -> Self {
+    if (seed[0] | seed[1] | seed[2] | seed[3]) == 0 {
+      Self::DEFAULT
+    } else {
+      Self { s: seed }
+    }
+  }
+
+  const DEFAULT: Self = Self {
+    s: [
+      DEFAULT_PCG_SEED as u64,
+      (DEFAULT_PCG_SEED >> 64) as u64,
+      DEFAULT_PCG_INC as u64,
+      (DEFAULT_PCG_INC >> 64) as u64,
+    ],
+  };
+
+  /// Gets the next 64-bits of output.
+  #[inline]
+  pub fn next_u64(&mut self) -> u64 {
+    let [s0, s1, s2, s3] = self.s;
+    let out = s0.wrapping_add(s3).rotate_left(23).wrapping_add(s0);
+    let t = s1 << 17;
+    let s2 = s2 ^ s0;
+    let s3 = s3 ^ s1;
+    let s1 = s1 ^ s2;
+    let s0 = s0 ^ s3;
+    let s2 = s2 ^ t;
+    let s3 = s3.rotate_left(45);
+    self.s = [s0, s1, s2, s3];
+    out
+  }
+
+  /// Jumps the generator by `2^128` steps forward.
+  ///
+  /// This can produce `2^128` non-overlapping sub-sequences for parallel work.
+  #[inline]
+  pub fn jump(&mut self) {
+    self.jump_by_poly(&XOSHIRO256_JUMP)
+  }
+
+  /// Jumps the generator by `2^192` steps forward.
+  ///
+  /// This can produce `2^64` starting points, each of which can then use
+  /// [`jump`](Self::jump) to make its own sub-sequences.
+  #[inline]
+  pub fn long_jump(&mut self) {
+    self.jump_by_poly(&XOSHIRO256_LONG_JUMP)
+  }
+
+  fn jump_by_poly(&mut self, poly: &[u64; 4]) {
+    let mut acc = [0_u64; 4];
+    for word in poly.iter() {
+      for b in 0..64 {
+        if (word & (1_u64 << b)) != 0 {
+          acc[0] ^= self.s[0];
+          acc[1] ^= self.s[1];
+          acc[2] ^= self.s[2];
+          acc[3] ^= self.s[3];
+        }
+        self.next_u64();
+      }
+    }
+    self.s = acc;
+  }
+}
+
+impl Default for Xoshiro256pp {
+  fn default() -> Self {
+    Self::DEFAULT
+  }
+}
+
+impl From<[u64; 4]> for Xoshiro256pp {
+  fn from(s: [u64; 4]) -> Self {
+    Self { s }
+  }
+}
+
+impl From<Xoshiro256pp> for [u64; 4] {
+  fn from(x: Xoshiro256pp) -> Self {
+    x.s
+  }
+}
+
+impl Gen64 for Xoshiro256pp {
+  fn next_u64(&mut self) -> u64 {
+    Xoshiro256pp::next_u64(self)
+  }
+}
+
+impl Gen32 for Xoshiro256pp {
+  fn next_u32(&mut self) -> u32 {
+    (Xoshiro256pp::next_u64(self) >> 32) as u32
+  }
+
+  fn next_u64(&mut self) -> u64 {
+    Xoshiro256pp::next_u64(self)
+  }
+}